@@ -0,0 +1,99 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use trm_sim::*;
+
+const ITERATIONS: u32 = 10_000;
+
+/**
+How `Mutation::apply` used to roll back: clone the whole game up front, restore the clone on error
+*/
+fn execute_with_clone_and_restore(action: &Action, game: &mut Game) -> ActionOutcome {
+    action.execute_with_snapshot(game)
+}
+
+fn execute_with_journal(action: &Action, game: &mut Game) -> ActionOutcome {
+    action.execute(game)
+}
+
+/**
+Executes the action once on each of the games, so that accepted actions always start from the same state
+*/
+fn time(
+    games: &mut [Game],
+    action: &Action,
+    execute: fn(&Action, &mut Game) -> ActionOutcome,
+) -> Duration {
+    let start = Instant::now();
+    for game in games.iter_mut() {
+        let _ = black_box(execute(action, game));
+    }
+    start.elapsed()
+}
+
+fn report(name: &str, game: &Game, action: &Action, is_accepted: bool) {
+    assert_eq!(action.execute(&mut game.clone()).is_ok(), is_accepted);
+
+    let per_iteration = |execute: fn(&Action, &mut Game) -> ActionOutcome| {
+        let mut games = vec![game.clone(); ITERATIONS as usize];
+        time(&mut games, action, execute).as_nanos() as f64 / ITERATIONS as f64
+    };
+    let clone_and_restore = per_iteration(execute_with_clone_and_restore);
+    let journal = per_iteration(execute_with_journal);
+    println!(
+        "{:<40} {:>14.0} ns {:>14.0} ns {:>9.2}x",
+        name,
+        clone_and_restore,
+        journal,
+        clone_and_restore / journal
+    );
+}
+
+fn main() {
+    let game = Game::new(&THARSIS, RuleSet::default());
+    let game_with_expansions = Game::new(
+        &THARSIS,
+        RuleSet {
+            venus_next: true,
+            colonies: true,
+            turmoil: true,
+            ..RuleSet::default()
+        },
+    );
+    let card_ids: Vec<CardId> = game.cards_in_hand().iter().copied().collect();
+
+    println!(
+        "{:<40} {:>17} {:>17} {:>10}",
+        "", "clone and restore", "journal", "speedup"
+    );
+    report(
+        "accepted action (sell 1 patent)",
+        &game,
+        &Action::SellPatents(vec![card_ids[0]]),
+        true,
+    );
+    report(
+        "accepted action (sell 10 patents)",
+        &game,
+        &Action::SellPatents(card_ids.clone()),
+        true,
+    );
+    report(
+        "rejected action (no change made)",
+        &game,
+        &Action::SellPatents(vec![-1]),
+        false,
+    );
+    report(
+        "rejected action (after 10 discards)",
+        &game,
+        &Action::SellPatents([card_ids.as_slice(), &[-1]].concat()),
+        false,
+    );
+    report(
+        "accepted action (pass, with expansions)",
+        &game_with_expansions,
+        &Action::Pass,
+        true,
+    );
+}
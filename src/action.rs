@@ -1,61 +1,62 @@
-use self::invalid_action::{ActionResult, InvalidActionError};
+use self::invalid_action::{ActionOutcome, InvalidActionError};
 use crate::model::card::card_compendium::CARD_COMPENDIUM;
+use crate::model::card::prelude_compendium::PRELUDE_COMPENDIUM;
 use crate::model::card::CardId;
+use crate::model::game::award::Award;
 use crate::model::game::board::tile::Tile;
+use crate::model::game::colony::ColonyTile;
+use crate::model::game::milestone::Milestone;
 use crate::model::game::mutation::Mutation;
+use crate::model::game::outcome::Outcome;
+use crate::model::game::phase::Phase;
+use crate::model::game::standard_project::StandardProjectKind;
+use crate::model::game::turmoil::Party;
 use crate::model::game::Game;
 use crate::model::resource::Resource;
-use lazy_static::lazy_static;
 
 pub(crate) mod invalid_action;
 
-lazy_static! {
-    static ref HEAT_CONVERSION: Mutation = Mutation::Composite(vec![
-        Mutation::Resource(Resource::Heat, -8),
-        Mutation::TemperatureIncrease(1)
-    ]);
-    static ref PLANT_CONVERSION: Mutation = Mutation::Composite(vec![
-        Mutation::Resource(Resource::Plant, -8),
-        Mutation::TilePlacement(Tile::Greenery)
-    ]);
-    static ref STANDARD_CITY: Mutation = Mutation::Composite(vec![
-        Mutation::Resource(Resource::MegaCredit, -25),
-        Mutation::TilePlacement(Tile::City),
-        Mutation::Production(Resource::MegaCredit, 1)
-    ]);
-    static ref STANDARD_GREENERY: Mutation = Mutation::Composite(vec![
-        Mutation::Resource(Resource::MegaCredit, -23),
-        Mutation::TilePlacement(Tile::Greenery)
-    ]);
-    static ref STANDARD_AQUIFER: Mutation = Mutation::Composite(vec![
-        Mutation::Resource(Resource::MegaCredit, -18),
-        Mutation::TilePlacement(Tile::Ocean),
-    ]);
-    static ref STANDARD_ASTEROID: Mutation = Mutation::Composite(vec![
-        Mutation::Resource(Resource::MegaCredit, -14),
-        Mutation::TemperatureIncrease(1)
-    ]);
-    static ref STANDARD_POWER_PLANT: Mutation = Mutation::Composite(vec![
-        Mutation::Resource(Resource::MegaCredit, -11),
-        Mutation::Production(Resource::Energy, 1)
-    ]);
-}
-
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Clone)]
 pub enum Action {
     Card(CardId),
-    StandardPowerPlant,
-    StandardAsteroid,
-    StandardAquifer,
-    StandardGreenery,
-    StandardCity,
+    Prelude(CardId),
+    StandardProject(StandardProjectKind),
+    /**
+    Discards the given cards from hand for 1 Mega Credit each
+    */
+    SellPatents(Vec<CardId>),
     HeatConversion,
     PlantConversion,
+    ClaimMilestone(Milestone),
+    FundAward(Award),
+    /**
+    Trades with a colony tile, paying with Mega Credits, energy or titanium
+    */
+    Trade(ColonyTile, Resource),
+    BuildColony(ColonyTile),
+    SendDelegate(Party),
     Pass,
 }
 
 impl Action {
-    pub fn execute(&self, game: &mut Game) -> ActionResult {
+    /**
+    Executes the action, or leaves the game untouched if the action is invalid
+    */
+    pub fn execute(&self, game: &mut Game) -> ActionOutcome {
+        let is_allowed = match game.phase() {
+            Phase::Prelude => matches!(self, Self::Prelude(_)),
+            Phase::Generation => !matches!(self, Self::Prelude(_)),
+            Phase::FinalGreeneryConversion => matches!(self, Self::PlantConversion | Self::Pass),
+            Phase::Over => return InvalidActionError::from("Game is over").into_err(),
+        };
+        if !is_allowed {
+            return InvalidActionError::new(format!(
+                "Action not allowed in the {:?} phase",
+                game.phase()
+            ))
+            .into_err();
+        }
+
         match self {
             Self::Card(card_id) => {
                 if let Some(card) = CARD_COMPENDIUM.get(card_id) {
@@ -65,14 +66,110 @@ impl Action {
                         .into_err()
                 }
             }
-            Self::StandardPowerPlant => STANDARD_POWER_PLANT.apply(game),
-            Self::StandardAsteroid => STANDARD_ASTEROID.apply(game),
-            Self::StandardAquifer => STANDARD_AQUIFER.apply(game),
-            Self::StandardGreenery => STANDARD_GREENERY.apply(game),
-            Self::StandardCity => STANDARD_CITY.apply(game),
-            Self::HeatConversion => HEAT_CONVERSION.apply(game),
-            Self::PlantConversion => PLANT_CONVERSION.apply(game),
+            Self::Prelude(card_id) => {
+                if let Some(prelude) = PRELUDE_COMPENDIUM.get(card_id) {
+                    prelude.play(game)
+                } else {
+                    InvalidActionError::new(format!("Prelude #{:0>2} does not exist", *card_id))
+                        .into_err()
+                }
+            }
+            Self::StandardProject(kind) => {
+                let standard_projects = game.standard_projects_handle();
+                match standard_projects.get(*kind) {
+                    Some(project) => project.mutation().apply(game),
+                    None => InvalidActionError::new(format!(
+                        "Standard project {:?} is not available",
+                        kind
+                    ))
+                    .into_err(),
+                }
+            }
+            Self::SellPatents(card_ids) => {
+                if card_ids.is_empty() {
+                    return InvalidActionError::from("No cards to sell").into_err();
+                }
+                Mutation::Composite(
+                    card_ids
+                        .iter()
+                        .flat_map(|card_id| {
+                            [
+                                Mutation::CardDiscard(*card_id),
+                                Mutation::Resource(Resource::MegaCredit, 1),
+                            ]
+                        })
+                        .collect(),
+                )
+                .apply(game)
+            }
+            Self::HeatConversion => Mutation::Composite(vec![
+                Mutation::Resource(Resource::Heat, -game.rules().heat_conversion_cost),
+                Mutation::TemperatureIncrease(1),
+            ])
+            .apply(game),
+            Self::PlantConversion => Mutation::Composite(vec![
+                Mutation::Resource(Resource::Plant, -game.rules().plant_conversion_cost),
+                Mutation::TilePlacement(Tile::Greenery),
+            ])
+            .apply(game),
+            Self::ClaimMilestone(milestone) => Mutation::MilestoneClaim(*milestone).apply(game),
+            Self::FundAward(award) => Mutation::AwardFunding(*award).apply(game),
+            Self::Trade(tile, payment) => {
+                let cost = match payment {
+                    Resource::MegaCredit => game.rules().trade_mega_credit_cost,
+                    Resource::Energy => game.rules().trade_energy_cost,
+                    Resource::Titanium => game.rules().trade_titanium_cost,
+                    _ => {
+                        return InvalidActionError::new(format!(
+                            "Trading cannot be paid with {:?}",
+                            payment
+                        ))
+                        .into_err()
+                    }
+                };
+                Mutation::Composite(vec![
+                    Mutation::Resource(payment.clone(), -cost),
+                    Mutation::Trade(*tile),
+                ])
+                .apply(game)
+            }
+            Self::BuildColony(tile) => Mutation::Composite(vec![
+                Mutation::Resource(Resource::MegaCredit, -game.rules().standard_colony_cost),
+                Mutation::ColonyBuild(*tile),
+            ])
+            .apply(game),
+            Self::SendDelegate(party) => Mutation::Composite(vec![
+                Mutation::Resource(Resource::MegaCredit, -game.rules().delegate_cost),
+                Mutation::DelegatePlacement(*party),
+            ])
+            .apply(game),
             Self::Pass => Mutation::Pass.apply(game),
         }
     }
+
+    /**
+    Executes the action the way invalid actions were rolled back before the journal:
+    the whole game is cloned up front and restored on error, with the journal paused.
+    Only kept as the baseline of the rollback benchmark.
+    */
+    #[doc(hidden)]
+    pub fn execute_with_snapshot(&self, game: &mut Game) -> ActionOutcome {
+        let snapshot = game.clone();
+        game.journal_mut().set_paused(true);
+        let result = self.execute(game);
+        game.journal_mut().set_paused(false);
+        if result.is_err() {
+            *game = snapshot;
+        }
+        result
+    }
+
+    /**
+    Executes the action on a copy of the game, returning the state it would lead to
+    */
+    pub fn simulate(&self, game: &Game) -> Result<(Game, Outcome), InvalidActionError> {
+        let mut simulated_game = game.clone();
+        let outcome = self.execute(&mut simulated_game)?;
+        Ok((simulated_game, outcome))
+    }
 }
@@ -1,9 +1,12 @@
 use std::error::Error;
 use std::fmt::{Display, Formatter};
 
+use crate::model::game::outcome::Outcome;
+
 #[derive(Debug)]
 pub struct InvalidActionError(String);
 pub type ActionResult = Result<(), InvalidActionError>;
+pub type ActionOutcome = Result<Outcome, InvalidActionError>;
 
 impl Display for InvalidActionError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -15,7 +18,7 @@ impl InvalidActionError {
     pub fn new(message: String) -> Self {
         Self(message)
     }
-    pub fn into_err(self) -> ActionResult {
+    pub fn into_err<T>(self) -> Result<T, Self> {
         Err(self)
     }
 }
@@ -1,14 +1,37 @@
-pub use action::invalid_action::{ActionResult, InvalidActionError};
+pub use action::invalid_action::{ActionOutcome, ActionResult, InvalidActionError};
 pub use action::Action;
 pub use model::card::card_compendium::ALL_CARD_IDS_IN_ASCENDING_ORDER;
 pub use model::card::CardId;
+pub use model::game::award::{Award, AWARDS, AWARD_COSTS};
 pub use model::game::board::game_map::THARSIS;
+pub use model::game::board::hex::Hex;
+pub use model::game::board::placement_strategy::{
+    AdjacencyMaximizing, CitySpotPreserving, PlacementStrategy, Random,
+};
+pub use model::game::board::tile::{Owner, PlayerId, Tile};
+pub use model::game::board::{Board, BoardPosition};
+pub use model::game::colony::{
+    Colony, ColonyTile, COLONY_TILES, MAX_COLONIES_PER_TILE, MAX_TRACK_POSITION,
+};
+pub use model::game::milestone::{Milestone, MILESTONES, MILESTONE_COST};
+pub use model::game::outcome::Outcome;
+pub use model::game::phase::Phase;
+pub use model::game::rule_set::{InvalidRuleSetError, RuleSet};
+pub use model::game::score_breakdown::ScoreBreakdown;
+pub use model::game::standard_project::{
+    StandardProject, StandardProjectCatalog, StandardProjectKind,
+};
+pub use model::game::turmoil::{
+    GlobalEvent, Party, Turmoil, DELEGATES, GLOBAL_EVENTS, PARTIES, REDS_TR_INCREASE_COST,
+};
 pub use model::game::Game;
 pub use model::game::{
     INITIAL_MEGA_CREDITS, INITIAL_OXYGEN, INITIAL_PRODUCTION, INITIAL_TEMPERATURE, INITIAL_TR,
-    LAST_GENERATION, MAX_OCEANS, MAX_OXYGEN, MAX_TEMPERATURE,
+    INITIAL_VENUS, LAST_GENERATION, MAX_OCEANS, MAX_OXYGEN, MAX_TEMPERATURE, MAX_VENUS,
+    PRELUDES_DEALT, PRELUDES_TO_PLAY, SOLO_PLAYER,
 };
-pub use model::resource::RESOURCES;
+pub use model::resource::{CardResource, Resource, RESOURCES};
+pub use model::tag::{Tag, TAGS};
 
 mod action;
 mod model;
@@ -6,21 +6,58 @@ fn map_user_input(input: &str) -> Result<Action, std::fmt::Error> {
         return Ok(Action::Card(card_id));
     }
 
+    if let Some(card_id) = input.strip_prefix("p ") {
+        return card_id
+            .trim()
+            .parse::<CardId>()
+            .map(Action::Prelude)
+            .map_err(|_| std::fmt::Error);
+    }
+    if let Some(card_ids) = input.strip_prefix("sp ") {
+        return card_ids
+            .split_whitespace()
+            .map(|card_id| card_id.parse::<CardId>().map_err(|_| std::fmt::Error))
+            .collect::<Result<Vec<CardId>, std::fmt::Error>>()
+            .map(Action::SellPatents);
+    }
+    if let Some(name) = input.strip_prefix("cm ") {
+        return Ok(Action::ClaimMilestone(name.trim().parse()?));
+    }
+    if let Some(name) = input.strip_prefix("fa ") {
+        return Ok(Action::FundAward(name.trim().parse()?));
+    }
+    if let Some(name) = input.strip_prefix("sd ") {
+        return Ok(Action::SendDelegate(name.trim().parse()?));
+    }
+    if let Some(name) = input.strip_prefix("bc ") {
+        return Ok(Action::BuildColony(name.trim().parse()?));
+    }
+    for (prefix, payment) in [
+        ("tm ", Resource::MegaCredit),
+        ("te ", Resource::Energy),
+        ("tt ", Resource::Titanium),
+    ] {
+        if let Some(name) = input.strip_prefix(prefix) {
+            return Ok(Action::Trade(name.trim().parse()?, payment));
+        }
+    }
+
     match input.to_lowercase().as_str() {
         "hc" => Ok(Action::HeatConversion),
         "pc" => Ok(Action::PlantConversion),
-        "sc" => Ok(Action::StandardCity),
-        "sg" => Ok(Action::StandardGreenery),
-        "st" => Ok(Action::StandardAsteroid),
-        "so" => Ok(Action::StandardAquifer),
-        "se" => Ok(Action::StandardPowerPlant),
+        "sc" => Ok(Action::StandardProject(StandardProjectKind::City)),
+        "sg" => Ok(Action::StandardProject(StandardProjectKind::Greenery)),
+        "st" => Ok(Action::StandardProject(StandardProjectKind::Asteroid)),
+        "so" => Ok(Action::StandardProject(StandardProjectKind::Aquifer)),
+        "se" => Ok(Action::StandardProject(StandardProjectKind::PowerPlant)),
+        "sv" => Ok(Action::StandardProject(StandardProjectKind::AirScrapping)),
         "pass" => Ok(Action::Pass),
-        _ => Err(std::fmt::Error::default()),
+        _ => Err(std::fmt::Error),
     }
 }
 
 fn main() {
-    let mut game = Game::new(&THARSIS);
+    let mut game = Game::new(&THARSIS, RuleSet::default());
     let mut user_input = String::new();
     println!("{}", game);
 
@@ -44,11 +81,16 @@ fn main() {
             Ok(action) => action,
         };
 
-        if let Err(error) = action.execute(&mut game) {
-            println!("Invalid action: {}", error);
-            continue;
-        } else if game.is_over() {
+        match action.execute(&mut game) {
+            Err(error) => {
+                println!("Invalid action: {}", error);
+                continue;
+            }
+            Ok(outcome) => print!("{}", outcome),
+        }
+        if game.is_over() {
             println!("{}", game);
+            println!("{}", game.score_breakdown());
             println!(
                 "Game over. {}.",
                 if game.is_won() {
@@ -1,8 +1,9 @@
-mod card_builder;
+pub(crate) mod card_builder;
 pub(crate) mod card_compendium;
+pub(crate) mod prelude_compendium;
 pub(crate) mod requirement;
 
-use crate::action::invalid_action::{ActionResult, InvalidActionError};
+use crate::action::invalid_action::{ActionOutcome, InvalidActionError};
 use crate::model::card::requirement::Requirement;
 use crate::model::game::mutation::Mutation;
 use crate::model::game::Game;
@@ -13,17 +14,27 @@ pub type CardId = i32;
 pub(crate) struct Card {
     mutation: Mutation,
     requirement: Option<Requirement>,
+    victory_points: i32,
 }
 
 impl Card {
-    pub(crate) fn new(mutation: Mutation, requirement: Option<Requirement>) -> Self {
+    pub(crate) fn new(
+        mutation: Mutation,
+        requirement: Option<Requirement>,
+        victory_points: i32,
+    ) -> Self {
         Self {
             mutation,
             requirement,
+            victory_points,
         }
     }
 
-    pub(crate) fn play(&self, game: &mut Game) -> ActionResult {
+    pub(crate) fn victory_points(&self) -> i32 {
+        self.victory_points
+    }
+
+    pub(crate) fn play(&self, game: &mut Game) -> ActionOutcome {
         if let Some(requirement) = &self.requirement {
             if !requirement.is_fulfilled(game) {
                 return Err(InvalidActionError::new(format!(
@@ -39,6 +50,9 @@ impl Card {
 impl Display for Card {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.mutation)?;
+        if self.victory_points != 0 {
+            writeln!(f, "{} victory point(s)", self.victory_points)?;
+        }
         if let Some(ref requirement) = self.requirement {
             writeln!(f, "Requirement: {}", requirement)?;
         }
@@ -1,18 +1,20 @@
 use crate::model::card::requirement::Requirement;
 use crate::model::card::{Card, CardId};
 use crate::model::game::mutation::Mutation;
-use crate::model::resource::Resource;
+use crate::model::resource::{CardResource, Resource};
 use crate::model::tag::Tag;
 use crate::model::tag::Tag::{Builder, Space};
 
 pub(crate) struct CardBuilder {
     card_id: CardId,
     cost: i32,
-    requirement: Option<Requirement>,
+    requirements: Vec<Requirement>,
     tags: Vec<Tag>,
     victory_points: i32,
     other_mutations: Vec<Mutation>,
+    card_resources: Option<(CardResource, i32)>,
     event: bool,
+    prelude: bool,
 }
 
 impl CardBuilder {
@@ -20,11 +22,13 @@ impl CardBuilder {
         Self {
             card_id: 0,
             cost: 0,
-            requirement: None,
+            requirements: Vec::new(),
             tags: Vec::new(),
             victory_points: 0,
             other_mutations: Vec::new(),
+            card_resources: None,
             event: false,
+            prelude: false,
         }
     }
 
@@ -48,8 +52,19 @@ impl CardBuilder {
         self
     }
 
+    /**
+    Prelude cards are played from the prelude hand at the start of the game, free of charge
+    */
+    pub(crate) fn prelude(mut self) -> Self {
+        self.prelude = true;
+        self
+    }
+
+    /**
+    Adds a requirement; a card with several requirements needs all of them fulfilled
+    */
     pub(crate) fn requirement(mut self, requirement: Requirement) -> Self {
-        self.requirement = Some(requirement);
+        self.requirements.push(requirement);
         self
     }
 
@@ -58,28 +73,52 @@ impl CardBuilder {
         self
     }
 
+    /**
+    Resources placed on the card itself when it is played
+    */
+    pub(crate) fn card_resources(mut self, resource: CardResource, amount: i32) -> Self {
+        self.card_resources = Some((resource, amount));
+        self
+    }
+
     pub(crate) fn victory_points(mut self, amount: i32) -> Self {
         self.victory_points += amount;
         self
     }
 
     pub(crate) fn build(mut self) -> Card {
-        let mut mutations = vec![
-            Mutation::CardPlay(self.card_id),
-            self.get_payment_mutation(),
-        ];
-
-        if self.victory_points > 0 {
-            mutations.push(Mutation::VictoryPoint(self.victory_points))
-        }
+        let mut mutations = if self.prelude {
+            vec![Mutation::PreludePlay(self.card_id)]
+        } else {
+            vec![
+                Mutation::CardPlay(self.card_id),
+                self.get_payment_mutation(),
+            ]
+        };
 
-        if !self.event {
+        // Event cards are turned face down once played, so only their event tag counts afterwards.
+        if self.event {
+            mutations.push(Mutation::Tag(Tag::Event));
+        } else {
             mutations.extend(self.tags.iter().map(|tag| Mutation::Tag(*tag)));
         }
 
         mutations.append(&mut self.other_mutations);
+        if let Some((resource, amount)) = self.card_resources {
+            mutations.push(Mutation::CardResource(self.card_id, resource, amount));
+        }
+
+        let requirement = match self.requirements.len() {
+            0 => None,
+            1 => self.requirements.pop(),
+            _ => Some(Requirement::All(self.requirements)),
+        };
 
-        Card::new(Mutation::Composite(mutations), self.requirement)
+        Card::new(
+            Mutation::Composite(mutations),
+            requirement,
+            self.victory_points,
+        )
     }
 
     fn get_payment_mutation(&self) -> Mutation {
@@ -5,9 +5,11 @@ use lazy_static::lazy_static;
 use crate::model::card::card_builder::CardBuilder;
 use crate::model::card::requirement::Requirement;
 use crate::model::card::{Card, CardId};
+use crate::model::game::board::game_map::NOCTIS_CITY_POSITION;
 use crate::model::game::board::tile::Tile;
 use crate::model::game::board::tile::Tile::{Greenery, Ocean};
 use crate::model::game::mutation::Mutation::*;
+use crate::model::resource::CardResource;
 use crate::model::resource::Resource::*;
 use crate::model::tag::Tag;
 use crate::model::tag::Tag::{Builder, City, Earth, Jovian, Microbe, Power, Science, Space};
@@ -75,7 +77,12 @@ fn build_card_compendium() -> HashMap<CardId, Card> {
         ),
         (
             17,
-            martian_city().cost(18).mutation(Production(MegaCredit, 3)),
+            CardBuilder::new()
+                .cost(18)
+                .tags(vec![City, Builder])
+                .mutation(Production(Energy, -1))
+                .mutation(Production(MegaCredit, 3))
+                .mutation(TilePlacementAt(Tile::City, NOCTIS_CITY_POSITION)),
         ),
         (
             18,
@@ -455,6 +462,14 @@ fn build_card_compendium() -> HashMap<CardId, Card> {
                 .mutation(Production(Plant, 2))
                 .mutation(Resource(Plant, -2)),
         ),
+        (
+            153,
+            CardBuilder::new()
+                .cost(12)
+                .tags(vec![Science])
+                .mutation(RequirementTolerance(2))
+                .victory_points(1),
+        ),
         (
             155,
             CardBuilder::new()
@@ -463,6 +478,13 @@ fn build_card_compendium() -> HashMap<CardId, Card> {
                 .requirement(Requirement::MaxTemperature(-14))
                 .mutation(Production(Plant, 2)),
         ),
+        (
+            157,
+            CardBuilder::new()
+                .cost(11)
+                .tags(vec![Microbe])
+                .card_resources(CardResource::Microbe, 3),
+        ),
         (
             158,
             CardBuilder::new()
@@ -0,0 +1,231 @@
+use std::collections::HashMap;
+
+use lazy_static::lazy_static;
+
+use crate::model::card::card_builder::CardBuilder;
+use crate::model::card::{Card, CardId};
+use crate::model::game::board::tile::Tile;
+use crate::model::game::board::tile::Tile::Ocean;
+use crate::model::game::mutation::Mutation::*;
+use crate::model::resource::Resource::*;
+use crate::model::tag::Tag;
+use crate::model::tag::Tag::{Builder, City, Earth, Jovian, Microbe, Power, Science, Space};
+
+lazy_static! {
+    pub(crate) static ref PRELUDE_COMPENDIUM: HashMap<CardId, Card> = build_prelude_compendium();
+}
+
+fn build_prelude_compendium() -> HashMap<CardId, Card> {
+    [
+        (
+            1,
+            prelude()
+                .tags(vec![Earth])
+                .mutation(Production(MegaCredit, 4))
+                .mutation(Resource(MegaCredit, 3)),
+        ),
+        (
+            2,
+            prelude()
+                .tags(vec![Power])
+                .mutation(TilePlacement(Ocean))
+                .mutation(Production(Energy, 2))
+                .mutation(Resource(MegaCredit, -3)),
+        ),
+        (
+            3,
+            prelude()
+                .tags(vec![Microbe])
+                .mutation(Production(Energy, 1))
+                .mutation(Production(Plant, 1))
+                .mutation(Resource(Plant, 2)),
+        ),
+        (
+            4,
+            prelude()
+                .tags(vec![Science])
+                .mutation(Production(Plant, 1))
+                .mutation(CardDraw(3)),
+        ),
+        (
+            5,
+            prelude()
+                .tags(vec![Tag::Plant])
+                .mutation(Production(Plant, 2))
+                .mutation(Production(MegaCredit, -1)),
+        ),
+        (
+            6,
+            prelude()
+                .tags(vec![Earth])
+                .mutation(Production(MegaCredit, 6))
+                .mutation(Resource(MegaCredit, -6)),
+        ),
+        (8, prelude().mutation(Resource(MegaCredit, 21))),
+        (
+            9,
+            prelude()
+                .tags(vec![Builder, City])
+                .mutation(Production(Plant, 1))
+                .mutation(TilePlacement(Tile::City)),
+        ),
+        (
+            13,
+            prelude()
+                .tags(vec![Jovian])
+                .mutation(Production(Titanium, 2))
+                .mutation(Resource(MegaCredit, -5)),
+        ),
+        (
+            14,
+            prelude()
+                .mutation(TilePlacement(Ocean))
+                .mutation(TilePlacement(Ocean)),
+        ),
+        (
+            15,
+            prelude()
+                .mutation(TemperatureIncrease(3))
+                .mutation(Resource(MegaCredit, -5)),
+        ),
+        (
+            16,
+            prelude()
+                .tags(vec![Jovian, Science])
+                .mutation(Production(Titanium, 1))
+                .mutation(CardDraw(1)),
+        ),
+        (
+            17,
+            prelude()
+                .mutation(Production(MegaCredit, -2))
+                .mutation(Resource(MegaCredit, 30)),
+        ),
+        (
+            18,
+            prelude()
+                .tags(vec![Builder])
+                .mutation(Production(Energy, 1))
+                .mutation(Production(Steel, 1))
+                .mutation(Resource(MegaCredit, 6)),
+        ),
+        (
+            19,
+            prelude()
+                .mutation(TemperatureIncrease(1))
+                .mutation(Resource(Titanium, 4))
+                .mutation(Resource(Steel, 4)),
+        ),
+        (
+            20,
+            prelude()
+                .mutation(Production(MegaCredit, 1))
+                .mutation(Production(Steel, 1))
+                .mutation(Production(Titanium, 1)),
+        ),
+        (
+            21,
+            prelude()
+                .tags(vec![Builder])
+                .mutation(Production(Steel, 2))
+                .mutation(Resource(Steel, 4)),
+        ),
+        (
+            22,
+            prelude()
+                .tags(vec![Builder])
+                .mutation(Production(Heat, 3))
+                .mutation(Resource(Heat, 3)),
+        ),
+        (
+            23,
+            prelude()
+                .tags(vec![Builder])
+                .mutation(Production(Steel, 1))
+                .mutation(Production(Heat, 2))
+                .mutation(Resource(Heat, 2)),
+        ),
+        (
+            24,
+            prelude()
+                .mutation(TR(1))
+                .mutation(Production(Plant, 1))
+                .mutation(Resource(MegaCredit, 5)),
+        ),
+        (
+            25,
+            prelude()
+                .tags(vec![Space])
+                .mutation(Production(Titanium, 1))
+                .mutation(Resource(Titanium, 4)),
+        ),
+        (
+            26,
+            prelude()
+                .tags(vec![Builder])
+                .mutation(Production(Heat, 2))
+                .mutation(TilePlacement(Ocean)),
+        ),
+        (
+            27,
+            prelude().tags(vec![Power]).mutation(Production(Energy, 3)),
+        ),
+        (
+            28,
+            prelude()
+                .tags(vec![Tag::Wild])
+                .mutation(Production(MegaCredit, 1))
+                .mutation(CardDraw(3)),
+        ),
+        (
+            29,
+            prelude()
+                .tags(vec![Builder, City])
+                .mutation(Production(MegaCredit, 2))
+                .mutation(TilePlacement(Tile::City)),
+        ),
+        (
+            30,
+            prelude()
+                .tags(vec![Builder])
+                .mutation(OxygenIncrease(2))
+                .mutation(Resource(Steel, 5)),
+        ),
+        (
+            31,
+            prelude()
+                .mutation(Production(MegaCredit, -1))
+                .mutation(Production(Plant, 1))
+                .mutation(Production(Energy, 1))
+                .mutation(Production(Heat, 1)),
+        ),
+        (
+            32,
+            prelude()
+                .tags(vec![Power])
+                .mutation(Production(Energy, 2))
+                .mutation(Resource(Steel, 4)),
+        ),
+        (
+            33,
+            prelude()
+                .mutation(Resource(Titanium, 3))
+                .mutation(Resource(Steel, 8))
+                .mutation(Resource(Plant, 3)),
+        ),
+        (
+            34,
+            prelude()
+                .tags(vec![Earth])
+                .mutation(TR(3))
+                .mutation(CardDraw(1)),
+        ),
+    ]
+    .into_iter()
+    .map(|(id, card_builder)| (id, card_builder.id(id).build()))
+    .collect()
+}
+
+fn prelude() -> CardBuilder {
+    CardBuilder::new().prelude()
+}
@@ -1,41 +1,262 @@
-use crate::model::game::Game;
+use crate::model::game::board::tile::Tile;
+use crate::model::game::{Game, SOLO_PLAYER, TEMPERATURE_STEP, VENUS_STEP};
+use crate::model::resource::{CardResource, Resource};
 use crate::model::tag::Tag;
 use std::fmt::{Display, Formatter};
 
+#[allow(dead_code)] // Not every kind of requirement is used by the compendium yet
 pub(crate) enum Requirement {
+    All(Vec<Requirement>),
+    Any(Vec<Requirement>),
     MinOxygen(i32),
     MaxOxygen(i32),
     MinTemperature(i32),
     MaxTemperature(i32),
     MinOceanCount(i32),
     MaxOceanCount(i32),
+    MinVenus(i32),
+    MaxVenus(i32),
     Tag(Tag, i32),
+    MinProduction(Resource, i32),
+    MinTR(i32),
+    MinCityCount(i32),
+    MinGreeneryCount(i32),
+    MinCardResources(CardResource, i32),
 }
 
 impl Requirement {
     pub(crate) fn is_fulfilled(&self, game: &Game) -> bool {
+        let tolerance = game.requirement_tolerance();
         match self {
-            Self::MinOxygen(amount) => game.oxygen() >= *amount,
-            Self::MaxOxygen(amount) => game.oxygen() <= *amount,
-            Self::MinTemperature(amount) => game.temperature() >= *amount,
-            Self::MaxTemperature(amount) => game.temperature() <= *amount,
-            Self::MinOceanCount(amount) => game.oceans() >= *amount,
-            Self::MaxOceanCount(amount) => game.oceans() <= *amount,
-            Self::Tag(tag, amount) => game.tag(*tag) >= *amount,
+            Self::All(requirements) => requirements.iter().all(|r| r.is_fulfilled(game)),
+            Self::Any(requirements) => requirements.iter().any(|r| r.is_fulfilled(game)),
+            Self::MinOxygen(amount) => game.oxygen() + tolerance >= *amount,
+            Self::MaxOxygen(amount) => game.oxygen() - tolerance <= *amount,
+            Self::MinTemperature(amount) => {
+                game.temperature() + tolerance * TEMPERATURE_STEP >= *amount
+            }
+            Self::MaxTemperature(amount) => {
+                game.temperature() - tolerance * TEMPERATURE_STEP <= *amount
+            }
+            Self::MinOceanCount(amount) => game.oceans() + tolerance >= *amount,
+            Self::MaxOceanCount(amount) => game.oceans() - tolerance <= *amount,
+            Self::MinVenus(amount) => game.venus() + tolerance * VENUS_STEP >= *amount,
+            Self::MaxVenus(amount) => game.venus() - tolerance * VENUS_STEP <= *amount,
+            Self::Tag(tag, amount) => Self::tag_count(game, *tag) >= *amount,
+            Self::MinProduction(resource, amount) => game.production(resource) >= *amount,
+            Self::MinTR(amount) => game.tr() >= *amount,
+            Self::MinCityCount(amount) => {
+                game.board().owned_tile_count(Tile::City, SOLO_PLAYER) as i32 >= *amount
+            }
+            Self::MinGreeneryCount(amount) => {
+                game.board().owned_tile_count(Tile::Greenery, SOLO_PLAYER) as i32 >= *amount
+            }
+            Self::MinCardResources(resource, amount) => game.card_resources(*resource) >= *amount,
         }
     }
+
+    /**
+    Counts the tags of played non-event cards, wild tags included
+    */
+    fn tag_count(game: &Game, tag: Tag) -> i32 {
+        match tag {
+            Tag::Wild => game.tag(Tag::Wild),
+            _ => game.tag(tag) + game.tag(Tag::Wild),
+        }
+    }
+
+    fn fmt_joined(
+        f: &mut Formatter<'_>,
+        requirements: &[Requirement],
+        separator: &str,
+    ) -> std::fmt::Result {
+        for (i, requirement) in requirements.iter().enumerate() {
+            if i > 0 {
+                write!(f, "{}", separator)?;
+            }
+            write!(f, "({})", requirement)?;
+        }
+        Ok(())
+    }
 }
 
 impl Display for Requirement {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
+            Self::All(requirements) => Self::fmt_joined(f, requirements, " and "),
+            Self::Any(requirements) => Self::fmt_joined(f, requirements, " or "),
             Self::MinOxygen(amount) => write!(f, "At least {}% oxygen", amount),
             Self::MaxOxygen(amount) => write!(f, "At most {}% oxygen", amount),
             Self::MinTemperature(amount) => write!(f, "At least {} degrees C", amount),
             Self::MaxTemperature(amount) => write!(f, "At most {} degrees C", amount),
             Self::MinOceanCount(amount) => write!(f, "At least {} oceans in play", amount),
             Self::MaxOceanCount(amount) => write!(f, "At most {} oceans in play", amount),
+            Self::MinVenus(amount) => write!(f, "At least {}% Venus", amount),
+            Self::MaxVenus(amount) => write!(f, "At most {}% Venus", amount),
             Self::Tag(tag, amount) => write!(f, "At least {} {} tags", amount, tag),
+            Self::MinProduction(resource, amount) => {
+                write!(f, "At least {} {:?} production", amount, resource)
+            }
+            Self::MinTR(amount) => write!(f, "At least {} TR", amount),
+            Self::MinCityCount(amount) => write!(f, "At least {} cities", amount),
+            Self::MinGreeneryCount(amount) => write!(f, "At least {} greeneries", amount),
+            Self::MinCardResources(resource, amount) => {
+                write!(f, "At least {} {:?} resources on cards", amount, resource)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::card::card_builder::CardBuilder;
+    use crate::model::card::card_compendium::CARD_COMPENDIUM;
+    use crate::model::game::mutation::Mutation;
+    use crate::model::game::rule_set::RuleSet;
+    use crate::THARSIS;
+    use rstest::{fixture, rstest};
+
+    impl Game {
+        fn apply_mutation(&mut self, mutation: &Mutation) {
+            assert!(mutation.apply(self).is_ok());
         }
     }
+
+    #[fixture]
+    fn game() -> Game {
+        Game::new(&THARSIS, RuleSet::default())
+    }
+
+    #[rstest]
+    fn test_combinators(game: Game) {
+        let fulfilled = || Requirement::MaxOxygen(5);
+        let unfulfilled = || Requirement::MinOxygen(5);
+
+        assert!(Requirement::All(vec![]).is_fulfilled(&game));
+        assert!(Requirement::All(vec![fulfilled(), fulfilled()]).is_fulfilled(&game));
+        assert!(!Requirement::All(vec![fulfilled(), unfulfilled()]).is_fulfilled(&game));
+
+        assert!(!Requirement::Any(vec![]).is_fulfilled(&game));
+        assert!(Requirement::Any(vec![unfulfilled(), fulfilled()]).is_fulfilled(&game));
+        assert!(!Requirement::Any(vec![unfulfilled(), unfulfilled()]).is_fulfilled(&game));
+
+        assert_eq!(
+            Requirement::Any(vec![
+                Requirement::MinTR(20),
+                Requirement::All(vec![unfulfilled(), fulfilled()])
+            ])
+            .to_string(),
+            "(At least 20 TR) or ((At least 5% oxygen) and (At most 5% oxygen))"
+        );
+    }
+
+    #[rstest]
+    fn test_player_state_requirements(game: Game) {
+        let rules = game.rules();
+        assert!(Requirement::MinTR(rules.initial_tr).is_fulfilled(&game));
+        assert!(!Requirement::MinTR(rules.initial_tr + 1).is_fulfilled(&game));
+        assert!(Requirement::MinProduction(Resource::Heat, 1).is_fulfilled(&game));
+        assert!(!Requirement::MinProduction(Resource::Heat, 2).is_fulfilled(&game));
+        assert!(Requirement::MinCityCount(0).is_fulfilled(&game));
+        assert!(!Requirement::MinCityCount(1).is_fulfilled(&game));
+        assert!(!Requirement::MinGreeneryCount(1).is_fulfilled(&game));
+    }
+
+    #[rstest]
+    fn test_card_resource_requirement(mut game: Game) {
+        game.cards_in_hand_mut().insert(1);
+        let requirement = Requirement::MinCardResources(CardResource::Microbe, 3);
+        assert!(!requirement.is_fulfilled(&game));
+
+        let card = CardBuilder::new()
+            .id(1)
+            .card_resources(CardResource::Microbe, 3)
+            .build();
+        assert!(card.play(&mut game).is_ok());
+        assert_eq!(game.resources_on_card(1), 3);
+        assert!(requirement.is_fulfilled(&game));
+        assert!(!Requirement::MinCardResources(CardResource::Microbe, 4).is_fulfilled(&game));
+        assert!(!Requirement::MinCardResources(CardResource::Animal, 1).is_fulfilled(&game));
+    }
+
+    #[rstest]
+    fn test_requirement_tolerance(mut game: Game) {
+        game.apply_mutation(&Mutation::OxygenIncrease(5));
+        game.apply_mutation(&Mutation::TemperatureIncrease(3));
+        let (oxygen, temperature) = (game.oxygen(), game.temperature());
+
+        let requirements = [
+            Requirement::MinOxygen(oxygen + 2),
+            Requirement::MaxOxygen(oxygen - 2),
+            Requirement::MinTemperature(temperature + 4),
+            Requirement::MaxTemperature(temperature - 4),
+            Requirement::MinOceanCount(2),
+        ];
+        assert!(requirements.iter().all(|r| !r.is_fulfilled(&game)));
+
+        game.apply_mutation(&Mutation::RequirementTolerance(2));
+        assert_eq!(game.requirement_tolerance(), 2);
+        assert!(requirements.iter().all(|r| r.is_fulfilled(&game)));
+        assert!(!Requirement::MinOxygen(oxygen + 3).is_fulfilled(&game));
+        assert!(!Requirement::MinTemperature(temperature + 6).is_fulfilled(&game));
+        assert!(!Requirement::MinOceanCount(3).is_fulfilled(&game));
+        assert!(!Requirement::Tag(Tag::Science, 1).is_fulfilled(&game));
+    }
+
+    #[rstest]
+    fn test_adaptation_technology_widens_requirements(mut game: Game) {
+        let requirement = Requirement::MinOxygen(2);
+        assert!(!requirement.is_fulfilled(&game));
+
+        game.cards_in_hand_mut().insert(153);
+        assert!(CARD_COMPENDIUM[&153].play(&mut game).is_ok());
+        assert_eq!(game.requirement_tolerance(), 2);
+        assert!(requirement.is_fulfilled(&game));
+        assert_eq!(game.tag(Tag::Science), 1);
+    }
+
+    #[rstest]
+    fn test_wild_tags_count_towards_any_tag_requirement(mut game: Game) {
+        game.apply_mutation(&Mutation::Tag(Tag::Science));
+        assert!(Requirement::Tag(Tag::Science, 1).is_fulfilled(&game));
+        assert!(!Requirement::Tag(Tag::Science, 2).is_fulfilled(&game));
+        assert!(!Requirement::Tag(Tag::Jovian, 1).is_fulfilled(&game));
+
+        game.apply_mutation(&Mutation::Tag(Tag::Wild));
+        assert!(Requirement::Tag(Tag::Science, 2).is_fulfilled(&game));
+        assert!(Requirement::Tag(Tag::Jovian, 1).is_fulfilled(&game));
+        assert!(!Requirement::Tag(Tag::Jovian, 2).is_fulfilled(&game));
+        assert!(Requirement::Tag(Tag::Wild, 1).is_fulfilled(&game));
+        assert!(!Requirement::Tag(Tag::Wild, 2).is_fulfilled(&game));
+    }
+
+    #[rstest]
+    fn test_event_tags_do_not_count_towards_tag_requirements(mut game: Game) {
+        game.cards_in_hand_mut().extend([0, 1]);
+        let event = CardBuilder::new().tags(vec![Tag::Science]).event().build();
+        assert!(event.play(&mut game).is_ok());
+        assert!(!Requirement::Tag(Tag::Science, 1).is_fulfilled(&game));
+        assert_eq!(game.tag(Tag::Event), 1);
+
+        let card = CardBuilder::new().id(1).tags(vec![Tag::Science]).build();
+        assert!(card.play(&mut game).is_ok());
+        assert!(Requirement::Tag(Tag::Science, 1).is_fulfilled(&game));
+    }
+
+    #[rstest]
+    fn test_card_builder_accumulates_requirements(mut game: Game) {
+        game.cards_in_hand_mut().insert(0);
+        let card = CardBuilder::new()
+            .requirement(Requirement::MaxOxygen(5))
+            .requirement(Requirement::MinTR(100))
+            .build();
+        assert!(card.play(&mut game).is_err());
+
+        let card = CardBuilder::new()
+            .requirement(Requirement::MaxOxygen(5))
+            .requirement(Requirement::MinTR(1))
+            .build();
+        assert!(card.play(&mut game).is_ok());
+    }
 }
@@ -1,20 +1,44 @@
 use std::cmp;
 use std::collections::{HashMap, HashSet};
 use std::fmt::{Display, Formatter};
+use std::sync::Arc;
 
+use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
 
 use board::Board;
 
 use crate::model::card::card_compendium::CARD_COMPENDIUM;
+use crate::model::card::prelude_compendium::PRELUDE_COMPENDIUM;
 use crate::model::card::CardId;
+use crate::model::game::award::Award;
 use crate::model::game::board::game_map::GameMap;
-use crate::model::resource::Resource;
+use crate::model::game::board::placement_strategy::PlacementStrategy;
+use crate::model::game::board::tile::{PlayerId, Tile};
+use crate::model::game::colony::{Colony, COLONY_TILES};
+use crate::model::game::milestone::{Milestone, MILESTONE_VICTORY_POINTS};
+use crate::model::game::mutation::journal::Journal;
+use crate::model::game::phase::Phase;
+use crate::model::game::rule_set::{InvalidRuleSetError, RuleSet};
+use crate::model::game::score_breakdown::ScoreBreakdown;
+use crate::model::game::standard_project::StandardProjectCatalog;
+use crate::model::game::turmoil::Turmoil;
 use crate::model::resource::Resource::*;
-use crate::model::tag::Tag;
+use crate::model::resource::{CardResource, Resource};
+use crate::model::tag::{Tag, TAGS};
 
+pub(crate) mod award;
 pub(crate) mod board;
+pub(crate) mod colony;
+pub(crate) mod milestone;
 pub(crate) mod mutation;
+pub(crate) mod outcome;
+pub(crate) mod phase;
+pub(crate) mod rule_set;
+pub(crate) mod score_breakdown;
+pub(crate) mod standard_project;
+pub(crate) mod turmoil;
 
 pub const INITIAL_TR: i32 = 14;
 pub const INITIAL_TEMPERATURE: i32 = -30;
@@ -28,17 +52,36 @@ pub const MAX_OCEANS: i32 = 9;
 
 pub const LAST_GENERATION: i32 = 14;
 
+pub const TEMPERATURE_STEP: i32 = 2;
+
+pub const INITIAL_VENUS: i32 = 0;
+pub const MAX_VENUS: i32 = 30;
+pub const VENUS_STEP: i32 = 2;
+
+/**
+The id of the only player of a solo game
+*/
+pub const SOLO_PLAYER: PlayerId = 0;
+
+pub const PRELUDES_DEALT: usize = 4;
+pub const PRELUDES_TO_PLAY: usize = 2;
+
 const MINIMUM_MEGA_CREDITS_PRODUCTION: i32 = -5;
 const MINIMUM_PRODUCTION_OF_NON_MEGA_CREDIT_RESOURCES: i32 = 0;
 
 #[derive(Clone)]
 pub struct Game {
+    rules: RuleSet,
+    standard_projects: Arc<StandardProjectCatalog>,
+
     generation: i32,
+    phase: Phase,
     tr: i32,
 
     oxygen: i32,
     temperature: i32,
     oceans: i32,
+    venus: i32,
 
     resources: HashMap<Resource, i32>,
     productions: HashMap<Resource, i32>,
@@ -46,29 +89,89 @@ pub struct Game {
     board: Board,
 
     tags: HashMap<Tag, i32>,
+    requirement_tolerance: i32,
 
     cards_in_hand: HashSet<CardId>,
     played_cards: HashSet<CardId>,
+    card_resources: HashMap<CardId, (CardResource, i32)>,
     cards_to_be_drawn: Vec<CardId>,
+    discarded_cards: Vec<CardId>,
+
+    preludes_in_hand: HashSet<CardId>,
+    played_preludes: HashSet<CardId>,
+
+    claimed_milestones: Vec<Milestone>,
+    funded_awards: Vec<Award>,
 
-    victory_points: i32,
+    colonies: Vec<Colony>,
+    trade_fleets_used: i32,
+
+    turmoil: Option<Turmoil>,
+
+    /**
+    Shuffles the discard pile into a new draw deck, seeded with the setup so that seeded games repeat
+    */
+    rng: StdRng,
+    journal: Journal,
 }
 
 impl Game {
-    pub fn new(map: &'static GameMap) -> Game {
+    /**
+    Panics if the rule set does not fit the map, see `try_new`
+    */
+    pub fn new(map: &'static GameMap, rules: RuleSet) -> Game {
+        Self::try_new(map, rules).expect("The rule set should fit the map")
+    }
+    pub fn try_new(map: &'static GameMap, rules: RuleSet) -> Result<Game, InvalidRuleSetError> {
+        rules.validate(map)?;
+
         let mut cards_to_be_drawn = Vec::from_iter(CARD_COMPENDIUM.keys().copied());
 
         let mut rng = rand::thread_rng();
         cards_to_be_drawn.shuffle(&mut rng);
 
-        Game {
+        let preludes_in_hand = if rules.preludes {
+            let mut preludes = Vec::from_iter(PRELUDE_COMPENDIUM.keys().copied());
+            preludes.shuffle(&mut rng);
+            preludes.into_iter().take(PRELUDES_DEALT).collect()
+        } else {
+            HashSet::new()
+        };
+
+        let colonies = if rules.colonies {
+            let mut tiles = COLONY_TILES.to_vec();
+            tiles.shuffle(&mut rng);
+            tiles
+                .into_iter()
+                .take(rules.colony_tiles_in_play)
+                .map(Colony::new)
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let mut board = Board::new(map);
+        let mut setup_rng = StdRng::seed_from_u64(rules.setup_seed.unwrap_or_else(|| rng.gen()));
+        board.place_neutral_cities(rules.neutral_cities, &mut setup_rng);
+        let turmoil = rules.turmoil.then(|| Turmoil::new(setup_rng.gen()));
+
+        Ok(Game {
+            rules,
+            standard_projects: Arc::new(StandardProjectCatalog::new(&rules)),
+
             generation: 1,
-            tr: INITIAL_TR,
-            oxygen: INITIAL_OXYGEN,
-            temperature: INITIAL_TEMPERATURE,
+            phase: if rules.preludes {
+                Phase::Prelude
+            } else {
+                Phase::Generation
+            },
+            tr: rules.initial_tr,
+            oxygen: rules.initial_oxygen,
+            temperature: rules.initial_temperature,
             oceans: 0,
+            venus: INITIAL_VENUS,
             resources: HashMap::from([
-                (MegaCredit, INITIAL_MEGA_CREDITS),
+                (MegaCredit, rules.initial_mega_credits),
                 (Steel, 0),
                 (Titanium, 0),
                 (Plant, 0),
@@ -76,25 +179,16 @@ impl Game {
                 (Heat, 0),
             ]),
             productions: HashMap::from([
-                (MegaCredit, INITIAL_PRODUCTION),
-                (Steel, INITIAL_PRODUCTION),
-                (Titanium, INITIAL_PRODUCTION),
-                (Plant, INITIAL_PRODUCTION),
-                (Energy, INITIAL_PRODUCTION),
-                (Heat, INITIAL_PRODUCTION),
-            ]),
-            board: Board::new(map),
-            tags: HashMap::from([
-                (Tag::Builder, 0),
-                (Tag::Space, 0),
-                (Tag::Earth, 0),
-                (Tag::Jovian, 0),
-                (Tag::Plant, 0),
-                (Tag::Microbe, 0),
-                (Tag::Power, 0),
-                (Tag::Science, 0),
-                (Tag::City, 0),
+                (MegaCredit, rules.initial_production),
+                (Steel, rules.initial_production),
+                (Titanium, rules.initial_production),
+                (Plant, rules.initial_production),
+                (Energy, rules.initial_production),
+                (Heat, rules.initial_production),
             ]),
+            board,
+            tags: TAGS.iter().map(|tag| (*tag, 0)).collect(),
+            requirement_tolerance: 0,
 
             cards_in_hand: (0..10)
                 .map(|_| {
@@ -104,19 +198,50 @@ impl Game {
                 })
                 .collect(),
             played_cards: HashSet::new(),
+            card_resources: HashMap::new(),
             cards_to_be_drawn,
+            discarded_cards: Vec::new(),
 
-            victory_points: INITIAL_TR,
-        }
+            preludes_in_hand,
+            played_preludes: HashSet::new(),
+
+            claimed_milestones: Vec::new(),
+            funded_awards: Vec::new(),
+
+            colonies,
+            trade_fleets_used: 0,
+
+            turmoil,
+
+            rng: setup_rng,
+            journal: Journal::default(),
+        })
+    }
+    pub fn with_placement_strategy(mut self, strategy: &'static dyn PlacementStrategy) -> Game {
+        self.board.set_placement_strategy(strategy);
+        self
+    }
+    pub fn rules(&self) -> &RuleSet {
+        &self.rules
+    }
+    pub fn standard_projects(&self) -> &StandardProjectCatalog {
+        &self.standard_projects
+    }
+    /**
+    Shares the catalog, so that a project can be looked up while the game is being mutated
+    */
+    pub(crate) fn standard_projects_handle(&self) -> Arc<StandardProjectCatalog> {
+        Arc::clone(&self.standard_projects)
     }
     pub fn is_over(&self) -> bool {
-        self.generation > LAST_GENERATION
+        self.phase == Phase::Over
     }
     pub fn is_won(&self) -> bool {
         self.is_over()
-            && self.oxygen == MAX_OXYGEN
-            && self.temperature == MAX_TEMPERATURE
-            && self.oceans == MAX_OCEANS
+            && self.oxygen == self.rules.max_oxygen
+            && self.temperature == self.rules.max_temperature
+            && self.oceans == self.rules.max_oceans
+            && (!self.rules.venus_next || self.venus == self.rules.max_venus)
     }
     pub fn resource(&self, resource: &Resource) -> i32 {
         *self.resources.get(resource).unwrap()
@@ -124,9 +249,18 @@ impl Game {
     pub fn production(&self, resource: &Resource) -> i32 {
         *self.productions.get(resource).unwrap()
     }
-    pub(crate) fn tag(&self, tag: Tag) -> i32 {
+    /**
+    Counts the tags of played cards, not taking wild tags into account
+    */
+    pub fn tag(&self, tag: Tag) -> i32 {
         *self.tags.get(&tag).unwrap()
     }
+    /**
+    The number of steps by which global parameter requirements are widened in the player's favour
+    */
+    pub fn requirement_tolerance(&self) -> i32 {
+        self.requirement_tolerance
+    }
     fn resource_mut(&mut self, resource: &Resource) -> &mut i32 {
         self.resources.get_mut(resource).unwrap()
     }
@@ -139,22 +273,113 @@ impl Game {
     pub fn oceans(&self) -> i32 {
         self.oceans
     }
+    pub fn venus(&self) -> i32 {
+        self.venus
+    }
     pub fn generation(&self) -> i32 {
         self.generation
     }
+    pub fn phase(&self) -> Phase {
+        self.phase
+    }
     pub fn tr(&self) -> i32 {
         self.tr
     }
+    /**
+    Victory points as scored at the end of the game, given the current state
+    */
     pub fn victory_points(&self) -> i32 {
-        self.victory_points
+        self.score_breakdown().total()
     }
     pub fn cards_in_hand(&self) -> &HashSet<CardId> {
         &self.cards_in_hand
     }
+    #[cfg(test)]
+    pub(crate) fn cards_in_hand_mut(&mut self) -> &mut HashSet<CardId> {
+        &mut self.cards_in_hand
+    }
+    /**
+    Counts the resources of the given type held on all played cards
+    */
+    pub fn card_resources(&self, resource: CardResource) -> i32 {
+        self.card_resources
+            .values()
+            .filter(|(held, _)| *held == resource)
+            .map(|(_, amount)| amount)
+            .sum()
+    }
+    pub fn resources_on_card(&self, card_id: CardId) -> i32 {
+        self.card_resources
+            .get(&card_id)
+            .map_or(0, |(_, amount)| *amount)
+    }
+    pub(crate) fn journal_mut(&mut self) -> &mut Journal {
+        &mut self.journal
+    }
+    pub(crate) fn board(&self) -> &Board {
+        &self.board
+    }
+    pub fn preludes_in_hand(&self) -> &HashSet<CardId> {
+        &self.preludes_in_hand
+    }
+    pub fn played_preludes(&self) -> &HashSet<CardId> {
+        &self.played_preludes
+    }
+    pub fn discarded_cards(&self) -> &[CardId] {
+        &self.discarded_cards
+    }
+    pub fn claimed_milestones(&self) -> &[Milestone] {
+        &self.claimed_milestones
+    }
+    pub fn funded_awards(&self) -> &[Award] {
+        &self.funded_awards
+    }
+    pub fn colonies(&self) -> &[Colony] {
+        &self.colonies
+    }
+    pub fn available_trade_fleets(&self) -> i32 {
+        self.rules.trade_fleets - self.trade_fleets_used
+    }
+    pub fn turmoil(&self) -> Option<&Turmoil> {
+        self.turmoil.as_ref()
+    }
+    pub fn score_breakdown(&self) -> ScoreBreakdown {
+        let mut card_victory_points: Vec<(CardId, i32)> = self
+            .played_cards
+            .iter()
+            .map(|card_id| {
+                (
+                    *card_id,
+                    CARD_COMPENDIUM.get(card_id).unwrap().victory_points(),
+                )
+            })
+            .filter(|(_, victory_points)| *victory_points != 0)
+            .collect();
+        card_victory_points.sort_unstable();
+
+        ScoreBreakdown::new(
+            self.tr,
+            card_victory_points,
+            self.board.owned_tile_count(Tile::Greenery, SOLO_PLAYER) as i32,
+            self.board.city_adjacency_victory_points(SOLO_PLAYER),
+            self.claimed_milestones.len() as i32 * MILESTONE_VICTORY_POINTS,
+            self.funded_awards
+                .iter()
+                .map(|award| award.victory_points(self))
+                .sum(),
+        )
+    }
 }
 
 impl Display for Game {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.phase == Phase::Prelude {
+            writeln!(f, "PRELUDES:")?;
+            for card_id in self.preludes_in_hand.iter() {
+                writeln!(f, "{}", PRELUDE_COMPENDIUM.get(card_id).unwrap())?;
+            }
+        }
+
         writeln!(f, "CARDS:")?;
         for card_id in self.cards_in_hand.iter() {
             writeln!(f, "{}", CARD_COMPENDIUM.get(card_id).unwrap())?;
@@ -163,18 +388,30 @@ impl Display for Game {
         writeln!(
             f,
             "Generation: {}",
-            cmp::min(LAST_GENERATION, self.generation)
+            cmp::min(self.rules.last_generation, self.generation)
         )?;
+        if self.phase == Phase::FinalGreeneryConversion {
+            writeln!(f, "Final greenery conversion: only plants can be converted")?;
+        }
         writeln!(
             f,
             "Terraforming Rating: {}; Victory Points: {}",
-            self.tr, self.victory_points
+            self.tr,
+            self.victory_points()
         )?;
         writeln!(
             f,
             "Oxygen: {} / {}%; Temperature: {:+} / {:+}C; Oceans: {} / {}",
-            self.oxygen, MAX_OXYGEN, self.temperature, MAX_TEMPERATURE, self.oceans, MAX_OCEANS
+            self.oxygen,
+            self.rules.max_oxygen,
+            self.temperature,
+            self.rules.max_temperature,
+            self.oceans,
+            self.rules.max_oceans
         )?;
+        if self.rules.venus_next {
+            writeln!(f, "Venus: {} / {}%", self.venus, self.rules.max_venus)?;
+        }
         writeln!(f)?;
 
         writeln!(f, "Resources / Production:")?;
@@ -217,13 +454,34 @@ impl Display for Game {
 
         writeln!(f)?;
         writeln!(f, "Tags: {:?}", self.tags)?;
+        writeln!(f, "Claimed milestones: {:?}", self.claimed_milestones)?;
+        writeln!(f, "Funded awards: {:?}", self.funded_awards)?;
+        if self.rules.colonies {
+            writeln!(
+                f,
+                "Colonies (trade fleets: {} / {}):",
+                self.available_trade_fleets(),
+                self.rules.trade_fleets
+            )?;
+            for colony in self.colonies.iter() {
+                writeln!(f, "{}", colony)?;
+            }
+        }
+        if let Some(turmoil) = &self.turmoil {
+            writeln!(f, "{}", turmoil)?;
+        }
+        write!(f, "Standard projects:")?;
+        for project in self.standard_projects.iter() {
+            write!(f, " {};", project)?;
+        }
+        writeln!(f)?;
         writeln!(f)?;
 
         writeln!(f, "{}", self.board)?;
 
         if self.is_over() {
             if self.is_won() {
-                writeln!(f, "You won with {} victory points!", self.victory_points)?;
+                writeln!(f, "You won with {} victory points!", self.victory_points())?;
             } else {
                 writeln!(f, "You lost.")?;
             }
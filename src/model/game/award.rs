@@ -0,0 +1,89 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::model::game::board::tile::Tile;
+use crate::model::game::rule_set::RuleSet;
+use crate::model::game::{Game, SOLO_PLAYER};
+use crate::model::resource::Resource;
+use crate::model::tag::Tag;
+
+/// The cost of funding the first, second and third award
+pub const AWARD_COSTS: [i32; 3] = [8, 14, 20];
+pub const AWARD_FIRST_PLACE_VICTORY_POINTS: i32 = 5;
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Award {
+    Landlord,
+    Banker,
+    Scientist,
+    Thermalist,
+    Miner,
+}
+
+pub const AWARDS: [Award; 5] = [
+    Award::Landlord,
+    Award::Banker,
+    Award::Scientist,
+    Award::Thermalist,
+    Award::Miner,
+];
+
+impl Award {
+    /**
+    Returns the quantity the award is competed for
+    */
+    pub fn score(&self, game: &Game) -> i32 {
+        match self {
+            Self::Landlord => {
+                (game.board.owned_tile_count(Tile::City, SOLO_PLAYER)
+                    + game.board.owned_tile_count(Tile::Greenery, SOLO_PLAYER))
+                    as i32
+            }
+            Self::Banker => game.production(&Resource::MegaCredit),
+            Self::Scientist => game.tag(Tag::Science),
+            Self::Thermalist => game.resource(&Resource::Heat),
+            Self::Miner => game.resource(&Resource::Steel) + game.resource(&Resource::Titanium),
+        }
+    }
+
+    /**
+    The score needed for first place in a solo game, as set by the rule set
+    */
+    pub fn solo_threshold(&self, rules: &RuleSet) -> i32 {
+        match self {
+            Self::Landlord => rules.landlord_threshold,
+            Self::Banker => rules.banker_threshold,
+            Self::Scientist => rules.scientist_threshold,
+            Self::Thermalist => rules.thermalist_threshold,
+            Self::Miner => rules.miner_threshold,
+        }
+    }
+
+    /**
+    Without opponents, the player takes first place by reaching the solo threshold
+    */
+    pub fn victory_points(&self, game: &Game) -> i32 {
+        if self.score(game) >= self.solo_threshold(game.rules()) {
+            AWARD_FIRST_PLACE_VICTORY_POINTS
+        } else {
+            0
+        }
+    }
+}
+
+impl Display for Award {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl FromStr for Award {
+    type Err = std::fmt::Error;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        AWARDS
+            .into_iter()
+            .find(|award| award.to_string().eq_ignore_ascii_case(name))
+            .ok_or(std::fmt::Error)
+    }
+}
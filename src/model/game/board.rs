@@ -1,10 +1,17 @@
 use std::fmt::{Display, Formatter};
 
+use rand::seq::SliceRandom;
+use rand::Rng;
+
 use game_map::GameMap;
-use tile::Tile;
+use hex::Hex;
+use placement_strategy::{AdjacencyMaximizing, PlacementStrategy};
 use tile::Tile::*;
+use tile::{Owner, PlayerId, Tile};
 
 pub(crate) mod game_map;
+pub(crate) mod hex;
+pub(crate) mod placement_strategy;
 pub(crate) mod tile;
 
 pub type BoardPosition = (usize, usize);
@@ -12,8 +19,9 @@ type VictoryPoints = i32;
 
 #[derive(Clone)]
 pub struct Board {
-    tiles: [Vec<Option<Tile>>; 9],
+    tiles: [Vec<Option<(Tile, Owner)>>; 9],
     game_map: &'static GameMap,
+    placement_strategy: &'static dyn PlacementStrategy,
 }
 
 impl Board {
@@ -31,193 +39,212 @@ impl Board {
                 vec![None; 5],
             ],
             game_map: map,
+            placement_strategy: &AdjacencyMaximizing,
         }
     }
 
-    pub fn place_tile_greedily(&mut self, tile: Tile) -> i32 {
-        match tile {
-            Ocean => {
-                self.place_ocean_greedily();
-                0
-            }
-            Greenery => self.place_greenery_greedily(),
-            City => self.place_city_greedily(),
-        }
+    pub(crate) fn set_placement_strategy(&mut self, strategy: &'static dyn PlacementStrategy) {
+        self.placement_strategy = strategy;
     }
 
     /**
-    Invariant: The maximum number of oceans has not yet been reached
-    */
-    fn place_ocean_greedily(&mut self) {
-        let (i, j) = self
-            .game_map
-            .ocean_positions()
-            .iter()
-            .find(|(i, j)| self.tiles[*i][*j].is_none())
-            .expect("There should be at least one empty ocean position");
-        self.tiles[*i][*j] = Some(Ocean);
-    }
-
-    fn place_greenery_greedily(&mut self) -> VictoryPoints {
-        let mut legal_positions = Vec::new();
-        for (i, row) in self.tiles.iter().enumerate() {
-            legal_positions.extend(
-                row.iter()
-                    .enumerate()
-                    .filter(|(j, _)| self.can_place_adjacent_greenery_at((i, *j)))
-                    .map(|(j, _)| (i, j)),
-            );
+    Places the player's tile where the placement strategy chooses among the legal positions.
+    Returns the position of the placed tile, or `None` if there was no legal position for it.
+    Victory points from tiles are not earned here, but scored over the final board.
+
+    Invariant: The maximum number of oceans has not yet been reached,
+    so with a validated rule set there is an empty ocean position left
+     */
+    pub fn place_tile(&mut self, tile: Tile, player: PlayerId) -> Option<BoardPosition> {
+        let legal_positions = self.legal_positions_for(tile, player);
+        assert!(
+            tile != Ocean || !legal_positions.is_empty(),
+            "There should be at least one empty ocean position"
+        );
+
+        let position = self
+            .placement_strategy
+            .choose_position(self, tile, &legal_positions)?;
+        self.put_tile(tile, player, position);
+        Some(position)
+    }
+
+    /**
+    Places the player's tile on the given position, which may be a position reserved for the card placing it.
+    Reserved positions disregard the usual placement restrictions, but never take an ocean.
+    Returns `None` if the position is off the board, taken, or not legal for the tile.
+     */
+    pub fn place_tile_at(
+        &mut self,
+        tile: Tile,
+        player: PlayerId,
+        position: BoardPosition,
+    ) -> Option<BoardPosition> {
+        let is_on_board = self
+            .tiles
+            .get(position.0)
+            .is_some_and(|row| position.1 < row.len());
+        if !is_on_board {
+            return None;
         }
 
-        if legal_positions.is_empty() {
-            for (i, row) in self.tiles.iter().enumerate() {
-                legal_positions.extend(
-                    row.iter()
-                        .enumerate()
-                        .filter(|(j, _)| self.can_place_non_ocean_tile_at((i, *j)))
-                        .map(|(j, _)| (i, j)),
-                );
-            }
+        let is_legal = if self.game_map.is_reserved_position(position) {
+            tile != Ocean && self.tile_at(position).is_none()
+        } else {
+            self.legal_positions_for(tile, player).contains(&position)
+        };
+        if !is_legal {
+            return None;
         }
 
-        legal_positions.sort_by(|x, y| {
-            self.get_neighbour_count_by_type(*x, City)
-                .cmp(&self.get_neighbour_count_by_type(*y, City))
-        });
+        self.put_tile(tile, player, position);
+        Some(position)
+    }
 
-        match legal_positions.last() {
-            None => 0,
-            Some((i, j)) => {
-                self.tiles[*i][*j] = Some(Greenery);
-                self.get_neighbour_count_by_type((*i, *j), City) as i32 + 1
-            }
-        }
+    fn put_tile(&mut self, tile: Tile, player: PlayerId, position: BoardPosition) {
+        let owner = if Tile::is_owned(tile) {
+            Owner::Player(player)
+        } else {
+            Owner::Neutral
+        };
+        self.tiles[position.0][position.1] = Some((tile, owner));
     }
 
-    fn can_place_adjacent_greenery_at(&self, position: (usize, usize)) -> bool {
-        self.can_place_non_ocean_tile_at(position)
-            && self.has_owned_tiles_around(position.0, position.1)
+    pub(crate) fn remove_tile(&mut self, position: BoardPosition) {
+        self.tiles[position.0][position.1] = None;
     }
 
     /**
-    Returns the victory points (not TR) earned from the tile placement
-     */
-    fn place_city_greedily(&mut self) -> i32 {
-        let mut legal_positions = Vec::new();
-        for (i, row) in self.tiles.iter().enumerate() {
-            for (j, _) in row.iter().enumerate() {
-                if self.can_place_city_at((i, j)) {
-                    legal_positions.push((i, j));
-                }
+    The solo setup: places neutral cities at random legal city positions, each with a neutral greenery next to it
+    */
+    pub(crate) fn place_neutral_cities(&mut self, count: i32, rng: &mut impl Rng) {
+        for _ in 0..count {
+            let city_positions = self.positions_where(|position| self.can_place_city_at(position));
+            let Some(city_position) = city_positions.choose(rng).copied() else {
+                return;
+            };
+            self.tiles[city_position.0][city_position.1] = Some((City, Owner::Neutral));
+
+            let greenery_positions: Vec<BoardPosition> =
+                Self::neighbour_positions_of(city_position.0, city_position.1)
+                    .into_iter()
+                    .filter(|position| self.is_free_land_position(*position))
+                    .collect();
+            if let Some(greenery_position) = greenery_positions.choose(rng) {
+                self.tiles[greenery_position.0][greenery_position.1] =
+                    Some((Greenery, Owner::Neutral));
             }
         }
+    }
 
-        legal_positions.sort_by(|x, y| {
-            self.get_neighbour_count_by_type(*x, Greenery)
-                .cmp(&self.get_neighbour_count_by_type(*y, Greenery))
-        });
-
-        match legal_positions.last() {
-            None => 0,
-            Some((i, j)) => {
-                self.tiles[*i][*j] = Some(City);
-                self.get_neighbour_count_by_type((*i, *j), Greenery) as i32
-            }
+    pub fn legal_positions_for(&self, tile: Tile, player: PlayerId) -> Vec<BoardPosition> {
+        match tile {
+            Ocean => self.positions_where(|position| {
+                self.tile_at(position).is_none() && self.game_map.is_ocean_position(position)
+            }),
+            Greenery => self.legal_greenery_positions(player),
+            City => self.positions_where(|position| self.can_place_city_at(position)),
         }
     }
 
-    fn can_place_city_at(&self, position: (usize, usize)) -> bool {
-        self.can_place_non_ocean_tile_at(position)
-            && self.get_neighbour_count_by_type(position, City) == 0
+    /**
+    Greeneries go on free land next to a tile the player owns.
+    Only if there is no such position, they may go on any free land.
+    */
+    fn legal_greenery_positions(&self, player: PlayerId) -> Vec<BoardPosition> {
+        let land_positions = self.positions_where(|position| self.is_free_land_position(position));
+        let adjacent_positions: Vec<BoardPosition> = land_positions
+            .iter()
+            .copied()
+            .filter(|position| self.has_owned_tiles_around(position.0, position.1, player))
+            .collect();
+        if adjacent_positions.is_empty() {
+            land_positions
+        } else {
+            adjacent_positions
+        }
     }
 
-    fn can_place_non_ocean_tile_at(&self, position: (usize, usize)) -> bool {
-        self.tiles[position.0][position.1].is_none()
-            && !self.game_map.ocean_positions().contains(&position)
+    /**
+    Counts the tiles of the given type in play, neutral ones included
+    */
+    pub fn tile_count(&self, tile: Tile) -> usize {
+        self.positions_where(|position| self.tile_at(position) == Some(tile))
+            .len()
     }
 
-    fn get_neighbour_count_by_type(&self, position: (usize, usize), tile_type: Tile) -> usize {
-        Self::neighbour_positions_of(position.0, position.1)
-            .into_iter()
-            .filter(|position| self.tiles[position.0][position.1] == Some(tile_type))
-            .count()
+    /**
+    Counts the player's tiles of the given type
+    */
+    pub fn owned_tile_count(&self, tile: Tile, player: PlayerId) -> usize {
+        self.positions_where(|position| {
+            self.tiles[position.0][position.1] == Some((tile, Owner::Player(player)))
+        })
+        .len()
     }
 
-    fn has_owned_tiles_around(&self, row: usize, column: usize) -> bool {
-        Self::neighbour_positions_of(row, column)
-            .iter()
-            .flat_map(|(i, j)| self.tiles[*i][*j])
-            .any(Tile::is_owned)
-    }
-
-    fn neighbour_positions_of(row: usize, column: usize) -> Vec<(usize, usize)> {
-        [
-            Self::top_right_neighbour(row, column),
-            Self::right_neighbour(row, column),
-            Self::bottom_right_neighbour(row, column),
-            Self::bottom_left_neighbour(row, column),
-            Self::left_neighbour(row, column),
-            Self::top_left_neighbour(row, column),
-        ]
+    /**
+    Returns the victory points earned at the end of the game from greeneries adjacent to the player's cities
+    */
+    pub fn city_adjacency_victory_points(&self, player: PlayerId) -> VictoryPoints {
+        self.positions_where(|position| {
+            self.tiles[position.0][position.1] == Some((City, Owner::Player(player)))
+        })
         .into_iter()
-        .flatten()
-        .collect()
+        .map(|position| self.get_neighbour_count_by_type(position, Greenery) as i32)
+        .sum()
     }
 
-    fn top_left_neighbour(row: usize, column: usize) -> Option<(usize, usize)> {
-        if row >= 5 {
-            Some((row - 1, column))
-        } else if row > 0 && column > 0 {
-            Some((row - 1, column - 1))
-        } else {
-            None
-        }
+    pub fn tile_at(&self, position: BoardPosition) -> Option<Tile> {
+        self.tiles[position.0][position.1].map(|(tile, _)| tile)
     }
 
-    fn left_neighbour(row: usize, column: usize) -> Option<(usize, usize)> {
-        if column > 0 {
-            Some((row, column - 1))
-        } else {
-            None
-        }
+    pub fn owner_at(&self, position: BoardPosition) -> Option<Owner> {
+        self.tiles[position.0][position.1].map(|(_, owner)| owner)
     }
 
-    fn bottom_left_neighbour(row: usize, column: usize) -> Option<(usize, usize)> {
-        if row <= 3 {
-            Some((row + 1, column))
-        } else if row <= 7 && column > 0 {
-            Some((row + 1, column - 1))
-        } else {
-            None
-        }
+    fn positions_where(&self, predicate: impl Fn(BoardPosition) -> bool) -> Vec<BoardPosition> {
+        self.tiles
+            .iter()
+            .enumerate()
+            .flat_map(|(i, row)| (0..row.len()).map(move |j| (i, j)))
+            .filter(|position| predicate(*position))
+            .collect()
     }
 
-    fn bottom_right_neighbour(row: usize, column: usize) -> Option<(usize, usize)> {
-        if row <= 3 {
-            Some((row + 1, column + 1))
-        } else if row <= 7 && column < 12 - row {
-            Some((row + 1, column))
-        } else {
-            None
-        }
+    pub fn can_place_city_at(&self, position: (usize, usize)) -> bool {
+        self.is_free_land_position(position)
+            && self.get_neighbour_count_by_type(position, City) == 0
     }
 
-    fn right_neighbour(row: usize, column: usize) -> Option<(usize, usize)> {
-        if column < 8 - row.abs_diff(4) {
-            Some((row, column + 1))
-        } else {
-            None
-        }
+    /**
+    An empty position that is neither reserved for oceans nor for a specific card
+    */
+    fn is_free_land_position(&self, position: (usize, usize)) -> bool {
+        self.tiles[position.0][position.1].is_none()
+            && !self.game_map.is_ocean_position(position)
+            && !self.game_map.is_reserved_position(position)
     }
 
-    fn top_right_neighbour(row: usize, column: usize) -> Option<(usize, usize)> {
-        if row >= 5 {
-            Some((row - 1, column + 1))
-        } else if row > 0 && column < row + 4 {
-            Some((row - 1, column))
-        } else {
-            None
-        }
+    pub fn get_neighbour_count_by_type(&self, position: (usize, usize), tile_type: Tile) -> usize {
+        Self::neighbour_positions_of(position.0, position.1)
+            .into_iter()
+            .filter(|position| self.tile_at(*position) == Some(tile_type))
+            .count()
+    }
+
+    fn has_owned_tiles_around(&self, row: usize, column: usize, player: PlayerId) -> bool {
+        Self::neighbour_positions_of(row, column)
+            .iter()
+            .any(|position| self.owner_at(*position) == Some(Owner::Player(player)))
+    }
+
+    pub fn neighbour_positions_of(row: usize, column: usize) -> Vec<(usize, usize)> {
+        Hex::from_offset((row, column))
+            .neighbours()
+            .iter()
+            .filter_map(Hex::to_offset)
+            .collect()
     }
 }
 
@@ -239,7 +266,10 @@ impl Display for Board {
                             "*"
                         }
                     )?,
-                    Some(tile) => write!(f, "{} ", *tile)?,
+                    Some((tile, Owner::Player(_))) => write!(f, "{} ", *tile)?,
+                    Some((tile, Owner::Neutral)) => {
+                        write!(f, "{} ", tile.to_string().to_lowercase())?
+                    }
                 }
             }
             writeln!(f)?;
@@ -253,7 +283,11 @@ mod tests {
     use std::collections::HashSet;
 
     use lazy_static::lazy_static;
+    use rand::rngs::StdRng;
+    use rand::seq::SliceRandom;
+    use rand::SeedableRng;
 
+    use crate::model::game::SOLO_PLAYER;
     use crate::THARSIS;
 
     use super::*;
@@ -274,4 +308,289 @@ mod tests {
             board_positions
         };
     }
+
+    /**
+    Property tests run over a fixed range of seeds, so that a failing board can be reproduced
+    */
+    fn random_board(tile_count: usize, seed: u64) -> Board {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut board = EMPTY_THARSIS_BOARD.clone();
+        let mut oceans = 0;
+
+        for _ in 0..tile_count {
+            let tile = *[City, Greenery, Ocean].choose(&mut rng).unwrap();
+            if tile == Ocean {
+                if oceans == THARSIS.ocean_positions().len() {
+                    continue;
+                }
+                oceans += 1;
+            }
+            board.place_tile(tile, SOLO_PLAYER);
+        }
+        board
+    }
+
+    fn tile_at(board: &Board, position: (usize, usize)) -> Option<Tile> {
+        board.tile_at(position)
+    }
+
+    #[test]
+    fn test_neighbour_symmetry() {
+        for &(row, column) in BOARD_POSITIONS.iter() {
+            for neighbour in Board::neighbour_positions_of(row, column) {
+                assert!(
+                    Board::neighbour_positions_of(neighbour.0, neighbour.1)
+                        .contains(&(row, column)),
+                    "{:?} is a neighbour of {:?}, but not vice versa",
+                    neighbour,
+                    (row, column)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_neighbours_are_on_the_board() {
+        for &(row, column) in BOARD_POSITIONS.iter() {
+            let neighbours = Board::neighbour_positions_of(row, column);
+            assert!(neighbours.iter().all(|n| BOARD_POSITIONS.contains(n)));
+            assert!(!neighbours.contains(&(row, column)));
+            assert_eq!(
+                neighbours.iter().collect::<HashSet<_>>().len(),
+                neighbours.len()
+            );
+        }
+    }
+
+    #[test]
+    fn test_neighbour_counts() {
+        for &(row, column) in BOARD_POSITIONS.iter() {
+            let neighbour_count = Board::neighbour_positions_of(row, column).len();
+            let is_on_edge = row == 0 || row == 8 || column == 0 || column == 8 - row.abs_diff(4);
+            if is_on_edge {
+                assert!((3..=4).contains(&neighbour_count));
+            } else {
+                assert_eq!(neighbour_count, 6);
+            }
+        }
+
+        assert_eq!(Board::neighbour_positions_of(0, 0).len(), 3);
+        assert_eq!(Board::neighbour_positions_of(0, 2).len(), 4);
+        assert_eq!(Board::neighbour_positions_of(4, 4).len(), 6);
+    }
+
+    #[test]
+    fn test_greedy_placement_never_places_adjacent_cities() {
+        for seed in 0..100 {
+            let board = random_board((seed % 70) as usize, seed);
+            for &position in BOARD_POSITIONS.iter() {
+                if tile_at(&board, position) == Some(City) {
+                    assert_eq!(
+                        board.get_neighbour_count_by_type(position, City),
+                        0,
+                        "Adjacent cities at {:?} with seed {}",
+                        position,
+                        seed
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_greedy_placement_respects_ocean_positions() {
+        for seed in 0..100 {
+            let board = random_board((seed % 70) as usize, seed);
+            for &position in BOARD_POSITIONS.iter() {
+                if let Some(tile) = tile_at(&board, position) {
+                    assert_eq!(
+                        tile == Ocean,
+                        THARSIS.is_ocean_position(position),
+                        "{:?} at {:?} with seed {}",
+                        tile,
+                        position,
+                        seed
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_greenery_is_placed_next_to_owned_tiles_when_possible() {
+        for seed in 1..100 {
+            let mut board = random_board((seed % 40) as usize, seed);
+            let had_free_owned_neighbourhood = BOARD_POSITIONS.iter().any(|&position| {
+                board.is_free_land_position(position)
+                    && board.has_owned_tiles_around(position.0, position.1, SOLO_PLAYER)
+            });
+            let previous_tiles = board.tiles.clone();
+
+            board.place_tile(Greenery, SOLO_PLAYER);
+
+            let placed_position = *BOARD_POSITIONS
+                .iter()
+                .find(|(row, column)| previous_tiles[*row][*column] != board.tiles[*row][*column])
+                .unwrap_or_else(|| panic!("A greenery should have been placed with seed {}", seed));
+            if had_free_owned_neighbourhood {
+                assert!(
+                    board.has_owned_tiles_around(placed_position.0, placed_position.1, SOLO_PLAYER),
+                    "Greenery at {:?} is not next to an owned tile with seed {}",
+                    placed_position,
+                    seed
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_city_adjacency_reflects_the_final_board() {
+        let mut board = EMPTY_THARSIS_BOARD.clone();
+        board.place_tile(City, SOLO_PLAYER);
+        assert_eq!(board.city_adjacency_victory_points(SOLO_PLAYER), 0);
+
+        board.place_tile(Greenery, SOLO_PLAYER);
+        board.place_tile(Greenery, SOLO_PLAYER);
+        assert_eq!(board.city_adjacency_victory_points(SOLO_PLAYER), 2);
+
+        let city_position = board.place_tile(City, SOLO_PLAYER).unwrap();
+        let adjacent_greeneries = board.get_neighbour_count_by_type(city_position, Greenery);
+        assert_eq!(
+            board.city_adjacency_victory_points(SOLO_PLAYER),
+            2 + adjacent_greeneries as i32
+        );
+        assert_eq!(board.tile_count(Greenery), 2);
+        assert_eq!(board.tile_count(City), 2);
+    }
+
+    #[test]
+    fn test_neutral_cities_are_not_the_players() {
+        let mut board = EMPTY_THARSIS_BOARD.clone();
+        board.place_neutral_cities(2, &mut StdRng::seed_from_u64(0));
+        assert_eq!(board.tile_count(City), 2);
+        assert_eq!(board.tile_count(Greenery), 2);
+        assert_eq!(board.owned_tile_count(City, SOLO_PLAYER), 0);
+        assert_eq!(board.owned_tile_count(Greenery, SOLO_PLAYER), 0);
+        assert_eq!(board.city_adjacency_victory_points(SOLO_PLAYER), 0);
+        assert!(BOARD_POSITIONS
+            .iter()
+            .all(|&position| !board.has_owned_tiles_around(position.0, position.1, SOLO_PLAYER)));
+
+        let city_position = board.place_tile(City, SOLO_PLAYER).unwrap();
+        assert_eq!(
+            board.owner_at(city_position),
+            Some(Owner::Player(SOLO_PLAYER))
+        );
+        assert_eq!(board.owned_tile_count(City, SOLO_PLAYER), 1);
+    }
+
+    #[test]
+    fn test_neutral_city_placement_is_seeded() {
+        let board_with_seed = |seed| {
+            let mut board = EMPTY_THARSIS_BOARD.clone();
+            board.place_neutral_cities(2, &mut StdRng::seed_from_u64(seed));
+            board.tiles
+        };
+        assert_eq!(board_with_seed(42), board_with_seed(42));
+    }
+
+    #[test]
+    fn test_tiles_are_owned_by_the_player_placing_them() {
+        let other_player = SOLO_PLAYER + 1;
+        let mut board = EMPTY_THARSIS_BOARD.clone();
+        let city_position = board.place_tile(City, other_player).unwrap();
+        assert_eq!(
+            board.owner_at(city_position),
+            Some(Owner::Player(other_player))
+        );
+
+        board.place_tile(Greenery, SOLO_PLAYER);
+        board.place_tile(Greenery, other_player);
+        assert_eq!(board.owned_tile_count(Greenery, SOLO_PLAYER), 1);
+        assert_eq!(board.owned_tile_count(Greenery, other_player), 1);
+        assert_eq!(board.owned_tile_count(City, SOLO_PLAYER), 0);
+        assert_eq!(board.city_adjacency_victory_points(SOLO_PLAYER), 0);
+
+        for position in board.legal_positions_for(Greenery, other_player) {
+            assert!(board.has_owned_tiles_around(position.0, position.1, other_player));
+        }
+    }
+
+    fn board_with_player_city_at(position: BoardPosition) -> Board {
+        let mut board = EMPTY_THARSIS_BOARD.clone();
+        board.tiles[position.0][position.1] = Some((City, Owner::Player(SOLO_PLAYER)));
+        board
+    }
+
+    #[test]
+    fn test_greenery_positions_are_the_free_land_next_to_owned_tiles() {
+        let board = board_with_player_city_at((2, 2));
+        let expected: HashSet<BoardPosition> = Board::neighbour_positions_of(2, 2)
+            .into_iter()
+            .filter(|position| !THARSIS.is_ocean_position(*position))
+            .collect();
+        assert_eq!(
+            board
+                .legal_positions_for(Greenery, SOLO_PLAYER)
+                .into_iter()
+                .collect::<HashSet<_>>(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_greenery_next_to_a_fully_surrounded_city_goes_on_any_free_land() {
+        let mut board = board_with_player_city_at((2, 2));
+        for (row, column) in Board::neighbour_positions_of(2, 2) {
+            board.tiles[row][column] = Some((Greenery, Owner::Neutral));
+        }
+
+        let legal_positions = board.legal_positions_for(Greenery, SOLO_PLAYER);
+        assert_eq!(
+            legal_positions.len(),
+            BOARD_POSITIONS
+                .iter()
+                .filter(|&&position| board.is_free_land_position(position))
+                .count()
+        );
+        assert!(legal_positions.iter().all(|position| {
+            board.tile_at(*position).is_none()
+                && !THARSIS.is_ocean_position(*position)
+                && !THARSIS.is_reserved_position(*position)
+        }));
+    }
+
+    #[test]
+    fn test_reserved_positions_are_never_legal() {
+        let board = board_with_player_city_at((4, 1));
+        assert!(Board::neighbour_positions_of(4, 1).contains(&(4, 2)));
+        for tile in [City, Greenery, Ocean] {
+            assert!(!board
+                .legal_positions_for(tile, SOLO_PLAYER)
+                .contains(&(4, 2)));
+        }
+
+        let mut board = EMPTY_THARSIS_BOARD.clone();
+        for _ in 0..40 {
+            board.place_tile(Greenery, SOLO_PLAYER);
+        }
+        assert_eq!(board.tile_at((4, 2)), None);
+    }
+
+    #[test]
+    fn test_reserved_position_is_taken_only_when_named() {
+        let mut board = board_with_player_city_at((4, 1));
+        assert_eq!(board.place_tile_at(City, SOLO_PLAYER, (4, 0)), None);
+        assert_eq!(board.place_tile_at(Ocean, SOLO_PLAYER, (4, 2)), None);
+        assert_eq!(board.place_tile_at(City, SOLO_PLAYER, (9, 0)), None);
+        assert_eq!(board.place_tile_at(City, SOLO_PLAYER, (4, 9)), None);
+
+        assert_eq!(board.place_tile_at(City, SOLO_PLAYER, (4, 2)), Some((4, 2)));
+        assert_eq!(board.owner_at((4, 2)), Some(Owner::Player(SOLO_PLAYER)));
+        assert_eq!(board.place_tile_at(City, SOLO_PLAYER, (4, 2)), None);
+        assert_eq!(
+            board.place_tile_at(Ocean, SOLO_PLAYER, (4, 3)),
+            Some((4, 3))
+        );
+    }
 }
@@ -2,26 +2,35 @@ use crate::model::game::board::BoardPosition;
 use lazy_static::lazy_static;
 use std::collections::HashSet;
 
+/**
+The position on Tharsis reserved for Noctis City
+*/
+pub(crate) const NOCTIS_CITY_POSITION: BoardPosition = (4, 2);
+
 lazy_static! {
-    pub static ref THARSIS: GameMap = GameMap::new(HashSet::from([
-        (0, 1),
-        (0, 3),
-        (0, 4),
-        (1, 5),
-        (3, 7),
-        (4, 3),
-        (4, 4),
-        (4, 5),
-        (5, 5),
-        (5, 6),
-        (5, 7),
-        (8, 4)
-    ]));
+    pub static ref THARSIS: GameMap = GameMap::new(
+        HashSet::from([
+            (0, 1),
+            (0, 3),
+            (0, 4),
+            (1, 5),
+            (3, 7),
+            (4, 3),
+            (4, 4),
+            (4, 5),
+            (5, 5),
+            (5, 6),
+            (5, 7),
+            (8, 4)
+        ]),
+        HashSet::from([NOCTIS_CITY_POSITION])
+    );
 }
 
 #[derive(Clone)]
 pub struct GameMap {
     ocean_positions: HashSet<(usize, usize)>,
+    reserved_positions: HashSet<(usize, usize)>,
 }
 
 impl GameMap {
@@ -33,7 +42,20 @@ impl GameMap {
         &self.ocean_positions
     }
 
-    fn new(ocean_positions: HashSet<(usize, usize)>) -> Self {
-        Self { ocean_positions }
+    /**
+    Reserved positions are kept for the card naming them, so no other tile may be placed there
+    */
+    pub fn is_reserved_position(&self, position: BoardPosition) -> bool {
+        self.reserved_positions.contains(&position)
+    }
+
+    fn new(
+        ocean_positions: HashSet<(usize, usize)>,
+        reserved_positions: HashSet<(usize, usize)>,
+    ) -> Self {
+        Self {
+            ocean_positions,
+            reserved_positions,
+        }
     }
 }
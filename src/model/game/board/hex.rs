@@ -0,0 +1,232 @@
+use std::cmp::{max, min};
+use std::ops::{Add, Mul};
+
+use crate::model::game::board::BoardPosition;
+
+/// Radius of the hexagonal board, i.e. the distance from the central hex to any edge hex.
+pub const BOARD_RADIUS: i32 = 4;
+
+/// Unit steps towards the six neighbours, clockwise starting from the top right one.
+const DIRECTIONS: [Hex; 6] = [
+    Hex::new(1, -1),
+    Hex::new(1, 0),
+    Hex::new(0, 1),
+    Hex::new(-1, 1),
+    Hex::new(-1, 0),
+    Hex::new(0, -1),
+];
+
+/**
+A hex in axial coordinates, with `r` growing downwards and `q` growing to the right.
+The third cube coordinate is derived as `s = -q - r`.
+The origin is the central hex of the board.
+*/
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Hex {
+    q: i32,
+    r: i32,
+}
+
+impl Hex {
+    pub const fn new(q: i32, r: i32) -> Self {
+        Self { q, r }
+    }
+
+    pub fn from_cube(q: i32, r: i32, s: i32) -> Self {
+        assert_eq!(q + r + s, 0, "Cube coordinates must sum up to 0");
+        Self { q, r }
+    }
+
+    /**
+    Converts the (row, column) position used to index the board rows into a hex.
+    Rows are numbered from the top, columns from the leftmost hex of each row.
+    */
+    pub fn from_offset((row, column): BoardPosition) -> Self {
+        let r = row as i32 - BOARD_RADIUS;
+        Self::new(column as i32 + Self::first_q_of_row(r), r)
+    }
+
+    /**
+    Returns the (row, column) position of the hex, or `None` if it lies outside the board
+    */
+    pub fn to_offset(&self) -> Option<BoardPosition> {
+        if self.distance(&Self::default()) > BOARD_RADIUS {
+            return None;
+        }
+        Some((
+            (self.r + BOARD_RADIUS) as usize,
+            (self.q - Self::first_q_of_row(self.r)) as usize,
+        ))
+    }
+
+    pub fn q(&self) -> i32 {
+        self.q
+    }
+
+    pub fn r(&self) -> i32 {
+        self.r
+    }
+
+    pub fn s(&self) -> i32 {
+        -self.q - self.r
+    }
+
+    pub fn cube(&self) -> (i32, i32, i32) {
+        (self.q, self.r, self.s())
+    }
+
+    pub fn neighbours(&self) -> [Hex; 6] {
+        DIRECTIONS.map(|direction| *self + direction)
+    }
+
+    pub fn distance(&self, other: &Hex) -> i32 {
+        let (q, r, s) = (*self + *other * -1).cube();
+        max(q.abs(), max(r.abs(), s.abs()))
+    }
+
+    /**
+    Returns the hexes at exactly `radius` steps away, clockwise starting from the left one
+    */
+    pub fn ring(&self, radius: i32) -> Vec<Hex> {
+        if radius <= 0 {
+            return vec![*self];
+        }
+
+        let mut hex = *self + DIRECTIONS[4] * radius;
+        let mut ring = Vec::with_capacity(6 * radius as usize);
+        for direction in DIRECTIONS {
+            for _ in 0..radius {
+                ring.push(hex);
+                hex = hex + direction;
+            }
+        }
+        ring
+    }
+
+    /**
+    Returns the hexes crossed by a straight line to `other`, both ends included
+    */
+    pub fn line_to(&self, other: &Hex) -> Vec<Hex> {
+        let distance = self.distance(other);
+        if distance == 0 {
+            return vec![*self];
+        }
+
+        // The nudge keeps points lying exactly on a hex edge from rounding inconsistently.
+        let (q1, r1) = (self.q as f64 + 1e-6, self.r as f64 + 1e-6);
+        let (q2, r2) = (other.q as f64 + 1e-6, other.r as f64 + 1e-6);
+        (0..=distance)
+            .map(|step| {
+                let t = step as f64 / distance as f64;
+                Self::round(q1 + (q2 - q1) * t, r1 + (r2 - r1) * t)
+            })
+            .collect()
+    }
+
+    fn round(q: f64, r: f64) -> Self {
+        let s = -q - r;
+        let (mut rounded_q, mut rounded_r, rounded_s) = (q.round(), r.round(), s.round());
+        let (q_diff, r_diff, s_diff) = (
+            (rounded_q - q).abs(),
+            (rounded_r - r).abs(),
+            (rounded_s - s).abs(),
+        );
+
+        if q_diff > r_diff && q_diff > s_diff {
+            rounded_q = -rounded_r - rounded_s;
+        } else if r_diff > s_diff {
+            rounded_r = -rounded_q - rounded_s;
+        }
+        Self::new(rounded_q as i32, rounded_r as i32)
+    }
+
+    fn first_q_of_row(r: i32) -> i32 {
+        -BOARD_RADIUS - min(r, 0)
+    }
+}
+
+impl Default for Hex {
+    fn default() -> Self {
+        Self::new(0, 0)
+    }
+}
+
+impl Add for Hex {
+    type Output = Hex;
+
+    fn add(self, other: Hex) -> Hex {
+        Hex::new(self.q + other.q, self.r + other.r)
+    }
+}
+
+impl Mul<i32> for Hex {
+    type Output = Hex;
+
+    fn mul(self, factor: i32) -> Hex {
+        Hex::new(self.q * factor, self.r * factor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_offset_conversion_round_trip() {
+        let row_lengths = [5, 6, 7, 8, 9, 8, 7, 6, 5];
+        for (row, length) in row_lengths.into_iter().enumerate() {
+            for column in 0..length {
+                let hex = Hex::from_offset((row, column));
+                assert!(hex.distance(&Hex::default()) <= BOARD_RADIUS);
+                assert_eq!(hex.to_offset(), Some((row, column)));
+            }
+        }
+        assert_eq!(Hex::new(1, -BOARD_RADIUS - 1).to_offset(), None);
+        assert_eq!(Hex::new(-3, -2).to_offset(), None);
+    }
+
+    #[test]
+    fn test_cube_coordinates() {
+        let hex = Hex::from_cube(2, -3, 1);
+        assert_eq!(hex.cube(), (2, -3, 1));
+        assert_eq!(hex.s(), 1);
+    }
+
+    #[test]
+    fn test_distance() {
+        let origin = Hex::default();
+        assert_eq!(origin.distance(&origin), 0);
+        assert!(origin
+            .neighbours()
+            .iter()
+            .all(|hex| hex.distance(&origin) == 1));
+        assert_eq!(
+            Hex::from_offset((0, 0)).distance(&Hex::from_offset((8, 4))),
+            8
+        );
+        assert_eq!(Hex::new(2, -1).distance(&Hex::new(-1, 3)), 4);
+    }
+
+    #[test]
+    fn test_ring() {
+        let center = Hex::new(1, -1);
+        assert_eq!(center.ring(0), vec![center]);
+        for radius in 1..=4 {
+            let ring = center.ring(radius);
+            assert_eq!(ring.len(), 6 * radius as usize);
+            assert!(ring.iter().all(|hex| hex.distance(&center) == radius));
+        }
+    }
+
+    #[test]
+    fn test_line() {
+        let start = Hex::from_offset((0, 0));
+        let end = Hex::from_offset((8, 0));
+        let line = start.line_to(&end);
+
+        assert_eq!(line.len(), start.distance(&end) as usize + 1);
+        assert_eq!(line.first(), Some(&start));
+        assert_eq!(line.last(), Some(&end));
+        assert!(line.windows(2).all(|pair| pair[0].distance(&pair[1]) == 1));
+    }
+}
@@ -0,0 +1,161 @@
+use rand::seq::SliceRandom;
+
+use crate::model::game::board::tile::Tile;
+use crate::model::game::board::tile::Tile::*;
+use crate::model::game::board::{Board, BoardPosition};
+
+/**
+Decides where a tile goes once the board has determined the legal positions for it.
+Implementations are stateless so that a single static instance can be shared by every board.
+*/
+pub trait PlacementStrategy: Sync {
+    /**
+    Returns one of the `legal_positions`, or `None` if there is none
+    */
+    fn choose_position(
+        &self,
+        board: &Board,
+        tile: Tile,
+        legal_positions: &[BoardPosition],
+    ) -> Option<BoardPosition>;
+}
+
+/**
+Places greeneries next to as many cities as possible and cities next to as many greeneries as possible
+*/
+pub struct AdjacencyMaximizing;
+
+/**
+Places greeneries and cities where they take away the fewest legal city positions
+*/
+pub struct CitySpotPreserving;
+
+/**
+Places tiles on a uniformly random legal position
+*/
+pub struct Random;
+
+impl PlacementStrategy for AdjacencyMaximizing {
+    fn choose_position(
+        &self,
+        board: &Board,
+        tile: Tile,
+        legal_positions: &[BoardPosition],
+    ) -> Option<BoardPosition> {
+        legal_positions
+            .iter()
+            .max_by_key(|position| adjacency_score(board, tile, **position))
+            .copied()
+    }
+}
+
+impl PlacementStrategy for CitySpotPreserving {
+    fn choose_position(
+        &self,
+        board: &Board,
+        tile: Tile,
+        legal_positions: &[BoardPosition],
+    ) -> Option<BoardPosition> {
+        legal_positions
+            .iter()
+            .max_by_key(|position| {
+                (
+                    -(lost_city_positions(board, tile, **position) as i32),
+                    adjacency_score(board, tile, **position),
+                )
+            })
+            .copied()
+    }
+}
+
+impl PlacementStrategy for Random {
+    fn choose_position(
+        &self,
+        _board: &Board,
+        _tile: Tile,
+        legal_positions: &[BoardPosition],
+    ) -> Option<BoardPosition> {
+        legal_positions.choose(&mut rand::thread_rng()).copied()
+    }
+}
+
+fn adjacency_score(board: &Board, tile: Tile, position: BoardPosition) -> usize {
+    match tile {
+        Greenery => board.get_neighbour_count_by_type(position, City),
+        City => board.get_neighbour_count_by_type(position, Greenery),
+        Ocean => 0,
+    }
+}
+
+/**
+Counts the positions where a city could be placed now, but not after placing the tile at `position`
+*/
+fn lost_city_positions(board: &Board, tile: Tile, position: BoardPosition) -> usize {
+    let occupied_city_position = usize::from(board.can_place_city_at(position));
+    let blocked_city_positions = if tile == City {
+        Board::neighbour_positions_of(position.0, position.1)
+            .into_iter()
+            .filter(|neighbour| board.can_place_city_at(*neighbour))
+            .count()
+    } else {
+        0
+    };
+    occupied_city_position + blocked_city_positions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::game::SOLO_PLAYER;
+    use crate::THARSIS;
+
+    fn board_with(strategy: &'static dyn PlacementStrategy, tiles: &[Tile]) -> Board {
+        let mut board = Board::new(&THARSIS);
+        board.set_placement_strategy(strategy);
+        for tile in tiles {
+            board.place_tile(*tile, SOLO_PLAYER);
+        }
+        board
+    }
+
+    #[test]
+    fn test_strategies_only_choose_legal_positions() {
+        let strategies: [&'static dyn PlacementStrategy; 3] =
+            [&AdjacencyMaximizing, &CitySpotPreserving, &Random];
+        for strategy in strategies {
+            let board = board_with(strategy, &[City, Greenery, City, Ocean, Greenery]);
+            for tile in [City, Greenery, Ocean] {
+                let legal_positions = board.legal_positions_for(tile, SOLO_PLAYER);
+                let position = strategy.choose_position(&board, tile, &legal_positions);
+                assert!(legal_positions.contains(&position.unwrap()));
+            }
+            assert_eq!(strategy.choose_position(&board, City, &[]), None);
+        }
+    }
+
+    #[test]
+    fn test_adjacency_maximizing_places_greenery_next_to_city() {
+        let board = board_with(&AdjacencyMaximizing, &[City]);
+        assert_eq!(
+            AdjacencyMaximizing
+                .choose_position(
+                    &board,
+                    Greenery,
+                    &board.legal_positions_for(Greenery, SOLO_PLAYER)
+                )
+                .map(|position| board.get_neighbour_count_by_type(position, City)),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn test_city_spot_preserving_places_greenery_off_city_spots() {
+        let board = board_with(&CitySpotPreserving, &[City]);
+        let legal_positions = board.legal_positions_for(Greenery, SOLO_PLAYER);
+        let position = CitySpotPreserving
+            .choose_position(&board, Greenery, &legal_positions)
+            .unwrap();
+        assert!(!board.can_place_city_at(position));
+        assert_eq!(lost_city_positions(&board, Greenery, position), 0);
+    }
+}
@@ -1,14 +1,28 @@
 use self::Tile::*;
 use std::fmt::{Display, Formatter};
 
-#[derive(Copy, Clone, PartialEq, Debug)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
 pub enum Tile {
     City,
     Greenery,
     Ocean,
 }
 
+pub type PlayerId = usize;
+
+/**
+Who a placed tile belongs to. Oceans belong to nobody, which counts as neutral.
+*/
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Owner {
+    Player(PlayerId),
+    Neutral,
+}
+
 impl Tile {
+    /**
+    Whether the tile belongs to whoever places it, as opposed to oceans
+    */
     pub fn is_owned(tile: Self) -> bool {
         matches!(tile, City | Greenery)
     }
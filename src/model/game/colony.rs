@@ -0,0 +1,248 @@
+use std::cmp::{max, min};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::model::game::board::tile::PlayerId;
+use crate::model::game::mutation::Mutation;
+use crate::model::resource::Resource;
+
+pub const INITIAL_TRACK_POSITION: usize = 1;
+pub const MAX_TRACK_POSITION: usize = 6;
+pub const MAX_COLONIES_PER_TILE: usize = 3;
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum ColonyTile {
+    Luna,
+    Ceres,
+    Io,
+    Ganymede,
+    Callisto,
+    Triton,
+    Pluto,
+}
+
+pub const COLONY_TILES: [ColonyTile; 7] = [
+    ColonyTile::Luna,
+    ColonyTile::Ceres,
+    ColonyTile::Io,
+    ColonyTile::Ganymede,
+    ColonyTile::Callisto,
+    ColonyTile::Triton,
+    ColonyTile::Pluto,
+];
+
+impl ColonyTile {
+    fn trade_income_track(&self) -> [i32; MAX_TRACK_POSITION + 1] {
+        match self {
+            Self::Luna => [1, 2, 4, 7, 10, 13, 17],
+            Self::Ceres => [1, 2, 3, 4, 6, 8, 10],
+            Self::Io => [2, 3, 4, 6, 8, 10, 13],
+            Self::Ganymede => [0, 1, 2, 3, 4, 5, 6],
+            Self::Callisto => [0, 2, 3, 5, 7, 10, 13],
+            Self::Triton => [0, 1, 1, 2, 3, 4, 5],
+            Self::Pluto => [0, 1, 2, 2, 3, 3, 4],
+        }
+    }
+
+    fn reward(&self, amount: i32) -> Mutation {
+        match self {
+            Self::Luna => Mutation::Resource(Resource::MegaCredit, amount),
+            Self::Ceres => Mutation::Resource(Resource::Steel, amount),
+            Self::Io => Mutation::Resource(Resource::Heat, amount),
+            Self::Ganymede => Mutation::Resource(Resource::Plant, amount),
+            Self::Callisto => Mutation::Resource(Resource::Energy, amount),
+            Self::Triton => Mutation::Resource(Resource::Titanium, amount),
+            Self::Pluto => Mutation::CardDraw(amount),
+        }
+    }
+
+    /**
+    What a trade yields with the marker at `track_position`
+    */
+    pub(crate) fn trade_income(&self, track_position: usize) -> Mutation {
+        self.reward(self.trade_income_track()[track_position])
+    }
+
+    /**
+    What each colony on the tile yields whenever the tile is traded with
+    */
+    pub(crate) fn colony_bonus(&self) -> Mutation {
+        match self {
+            Self::Luna | Self::Ceres | Self::Io => self.reward(2),
+            Self::Callisto => self.reward(3),
+            Self::Ganymede | Self::Triton | Self::Pluto => self.reward(1),
+        }
+    }
+
+    /**
+    What building a colony on the tile yields once
+    */
+    pub(crate) fn build_bonus(&self) -> Mutation {
+        match self {
+            Self::Luna => Mutation::Production(Resource::MegaCredit, 2),
+            Self::Ceres => Mutation::Production(Resource::Steel, 1),
+            Self::Io => Mutation::Production(Resource::Heat, 1),
+            Self::Ganymede => Mutation::Production(Resource::Plant, 1),
+            Self::Callisto => Mutation::Production(Resource::Energy, 1),
+            Self::Triton => self.reward(3),
+            Self::Pluto => self.reward(2),
+        }
+    }
+}
+
+impl Display for ColonyTile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl FromStr for ColonyTile {
+    type Err = std::fmt::Error;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        COLONY_TILES
+            .into_iter()
+            .find(|tile| tile.to_string().eq_ignore_ascii_case(name))
+            .ok_or(std::fmt::Error)
+    }
+}
+
+/**
+A colony tile in play, with its trade income marker and the colonies built on it
+*/
+#[derive(Clone, Debug)]
+pub struct Colony {
+    tile: ColonyTile,
+    track_position: usize,
+    owners: Vec<PlayerId>,
+}
+
+impl Colony {
+    pub(crate) fn new(tile: ColonyTile) -> Self {
+        Self {
+            tile,
+            track_position: INITIAL_TRACK_POSITION,
+            owners: Vec::new(),
+        }
+    }
+
+    pub fn tile(&self) -> ColonyTile {
+        self.tile
+    }
+
+    pub fn track_position(&self) -> usize {
+        self.track_position
+    }
+
+    pub fn colonies(&self) -> usize {
+        self.owners.len()
+    }
+
+    /**
+    Each player may have only one colony on a tile
+    */
+    pub fn has_colony_of(&self, player: PlayerId) -> bool {
+        self.owners.contains(&player)
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.colonies() == MAX_COLONIES_PER_TILE
+    }
+
+    /**
+    Adds the player's colony, which pushes the marker past the occupied colony spaces, and returns the build bonus
+    */
+    pub(crate) fn build(&mut self, player: PlayerId) -> Mutation {
+        assert!(
+            !self.is_full(),
+            "{} has no room left for a colony",
+            self.tile
+        );
+        assert!(
+            !self.has_colony_of(player),
+            "Player {} already has a colony on {}",
+            player,
+            self.tile
+        );
+        self.owners.push(player);
+        self.track_position = max(self.track_position, self.colonies());
+        self.tile.build_bonus()
+    }
+
+    /**
+    Moves the marker back to the first free colony space and returns the trade income plus the colony bonus
+    if the trading player has a colony on the tile
+    */
+    pub(crate) fn trade(&mut self, player: PlayerId) -> Mutation {
+        let mut mutations = vec![self.tile.trade_income(self.track_position)];
+        if self.has_colony_of(player) {
+            mutations.push(self.tile.colony_bonus());
+        }
+        self.track_position = self.colonies();
+        Mutation::Composite(mutations)
+    }
+
+    pub(crate) fn advance_track(&mut self) {
+        self.track_position = min(self.track_position + 1, MAX_TRACK_POSITION);
+    }
+}
+
+impl Display for Colony {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} (track: {} / {}, colonies: {} / {})",
+            self.tile,
+            self.track_position,
+            MAX_TRACK_POSITION,
+            self.colonies(),
+            MAX_COLONIES_PER_TILE
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_track_advances_up_to_the_end() {
+        let mut colony = Colony::new(ColonyTile::Luna);
+        for _ in 0..10 {
+            colony.advance_track();
+        }
+        assert_eq!(colony.track_position(), MAX_TRACK_POSITION);
+    }
+
+    #[test]
+    fn test_building_and_trading_move_the_marker() {
+        let mut colony = Colony::new(ColonyTile::Ceres);
+        colony.build(0);
+        colony.build(1);
+        assert_eq!(colony.track_position(), 2);
+
+        colony.advance_track();
+        colony.trade(0);
+        assert_eq!(colony.track_position(), 2);
+
+        colony.build(2);
+        assert!(colony.is_full());
+        assert_eq!(colony.track_position(), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_one_colony_per_player_and_tile() {
+        let mut colony = Colony::new(ColonyTile::Io);
+        colony.build(0);
+        assert!(colony.has_colony_of(0));
+        assert!(!colony.has_colony_of(1));
+        colony.build(0);
+    }
+
+    #[test]
+    fn test_colony_tile_from_str() {
+        assert_eq!("pluto".parse::<ColonyTile>(), Ok(ColonyTile::Pluto));
+        assert!("Mars".parse::<ColonyTile>().is_err());
+    }
+}
@@ -0,0 +1,56 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::model::game::board::tile::Tile;
+use crate::model::game::{Game, SOLO_PLAYER};
+use crate::model::tag::Tag;
+
+pub const MILESTONE_COST: i32 = 8;
+pub const MILESTONE_VICTORY_POINTS: i32 = 5;
+pub const MAX_CLAIMED_MILESTONES: usize = 3;
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Milestone {
+    Terraformer,
+    Mayor,
+    Gardener,
+    Builder,
+    Planner,
+}
+
+pub const MILESTONES: [Milestone; 5] = [
+    Milestone::Terraformer,
+    Milestone::Mayor,
+    Milestone::Gardener,
+    Milestone::Builder,
+    Milestone::Planner,
+];
+
+impl Milestone {
+    pub fn is_reached(&self, game: &Game) -> bool {
+        match self {
+            Self::Terraformer => game.tr() >= 35,
+            Self::Mayor => game.board.owned_tile_count(Tile::City, SOLO_PLAYER) >= 3,
+            Self::Gardener => game.board.owned_tile_count(Tile::Greenery, SOLO_PLAYER) >= 3,
+            Self::Builder => game.tag(Tag::Builder) >= 8,
+            Self::Planner => game.cards_in_hand().len() >= 16,
+        }
+    }
+}
+
+impl Display for Milestone {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl FromStr for Milestone {
+    type Err = std::fmt::Error;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        MILESTONES
+            .into_iter()
+            .find(|milestone| milestone.to_string().eq_ignore_ascii_case(name))
+            .ok_or(std::fmt::Error)
+    }
+}
@@ -1,13 +1,21 @@
+pub(crate) mod journal;
 mod mutation_helper;
 
 use std::fmt::{Display, Formatter};
 
-use crate::action::invalid_action::{ActionResult, InvalidActionError};
+use crate::action::invalid_action::{ActionOutcome, ActionResult, InvalidActionError};
 use crate::model::card::CardId;
+use crate::model::game::award::Award;
 use crate::model::game::board::tile::Tile;
+use crate::model::game::board::BoardPosition;
+use crate::model::game::colony::ColonyTile;
+use crate::model::game::milestone::Milestone;
+use crate::model::game::mutation::journal::{Change, Journal};
+use crate::model::game::outcome::Outcome;
+use crate::model::game::turmoil::Party;
 use crate::model::game::Game;
-use crate::model::resource::Resource;
 use crate::model::resource::Resource::*;
+use crate::model::resource::{CardResource, Resource};
 use crate::model::tag::Tag;
 
 #[derive(Clone)]
@@ -20,11 +28,21 @@ pub(crate) enum Mutation {
     TR(i32),
     TemperatureIncrease(i32),
     OxygenIncrease(i32),
-    VictoryPoint(i32),
+    VenusIncrease(i32),
     TilePlacement(Tile),
+    TilePlacementAt(Tile, BoardPosition),
+    RequirementTolerance(i32),
     Tag(Tag),
     CardDraw(i32),
     CardPlay(CardId),
+    CardResource(CardId, CardResource, i32),
+    CardDiscard(CardId),
+    PreludePlay(CardId),
+    MilestoneClaim(Milestone),
+    AwardFunding(Award),
+    ColonyBuild(ColonyTile),
+    Trade(ColonyTile),
+    DelegatePlacement(Party),
     Pass,
 }
 
@@ -32,52 +50,77 @@ const STEEL_VALUE: i32 = 2;
 const TITANIUM_VALUE: i32 = 3;
 
 impl Mutation {
-    pub(crate) fn apply(&self, game: &mut Game) -> ActionResult {
+    pub(crate) fn apply(&self, game: &mut Game) -> ActionOutcome {
         if game.is_over() {
             return InvalidActionError::from("Game is over").into_err();
         }
 
-        let game_clone = game.clone();
-        let result = self.unsafe_apply(game);
+        debug_assert!(game.journal.is_empty(), "Mutations should not be nested");
+        let mut outcome = Outcome::default();
+        let result = self.unsafe_apply(game, &mut outcome);
         if result.is_err() {
-            *game = game_clone;
+            Journal::roll_back(game);
         }
-
-        result
+        game.journal.clear();
+        result.map(|()| outcome)
     }
 
-    fn unsafe_apply(&self, game: &mut Game) -> ActionResult {
+    fn unsafe_apply(&self, game: &mut Game, outcome: &mut Outcome) -> ActionResult {
         match self {
             Mutation::Composite(mutations) => {
                 for mutation in mutations {
-                    mutation.unsafe_apply(game)?;
+                    mutation.unsafe_apply(game, outcome)?;
                 }
             }
             Mutation::Production(resource, delta) => {
-                mutation_helper::production_change(game, resource, *delta)?
+                mutation_helper::production_change(game, resource, *delta, outcome)?
             }
             Mutation::Resource(resource, delta) => {
-                mutation_helper::resource_change(game, resource, *delta)?
+                mutation_helper::resource_change(game, resource, *delta, outcome)?
             }
-            Mutation::TR(amount) => mutation_helper::increase_tr(game, *amount),
+            Mutation::TR(amount) => mutation_helper::increase_tr(game, *amount, outcome)?,
             Mutation::TemperatureIncrease(amount) => {
-                mutation_helper::increase_temperature_if_not_maxed_out(game, *amount)
+                mutation_helper::increase_temperature_if_not_maxed_out(game, *amount, outcome)?
             }
             Mutation::OxygenIncrease(amount) => {
-                mutation_helper::increase_oxygen_if_not_maxed_out(game, *amount)
+                mutation_helper::increase_oxygen_if_not_maxed_out(game, *amount, outcome)?
+            }
+            Mutation::VenusIncrease(amount) => {
+                mutation_helper::increase_venus_if_not_maxed_out(game, *amount, outcome)?
+            }
+            Mutation::TilePlacement(tile) => mutation_helper::place_tile(game, tile, outcome)?,
+            Mutation::TilePlacementAt(tile, position) => {
+                mutation_helper::place_tile_at(game, tile, *position, outcome)?
+            }
+            Mutation::RequirementTolerance(steps) => {
+                game.requirement_tolerance += steps;
+                game.journal.record(Change::RequirementTolerance(*steps));
+            }
+            Mutation::Tag(tag) => {
+                *game.tags.get_mut(tag).expect("Tag should be in the map") += 1;
+                game.journal.record(Change::Tag(*tag));
             }
-            Mutation::VictoryPoint(amount) => game.victory_points += amount,
-            Mutation::TilePlacement(tile) => mutation_helper::place_tile_greedily(game, tile),
-            Mutation::Tag(tag) => *game.tags.get_mut(tag).expect("Tag should be in the map") += 1,
-            Mutation::CardDraw(amount) => mutation_helper::draw_cards(game, *amount),
+            Mutation::CardDraw(amount) => mutation_helper::draw_cards(game, *amount, outcome),
             Mutation::CardPlay(card_id) => mutation_helper::play_card(game, *card_id)?,
+            Mutation::CardResource(card_id, resource, delta) => {
+                mutation_helper::card_resource_change(game, *card_id, *resource, *delta)?
+            }
+            Mutation::CardDiscard(card_id) => mutation_helper::discard_card(game, *card_id)?,
+            Mutation::PreludePlay(card_id) => mutation_helper::play_prelude(game, *card_id)?,
             Mutation::BuilderCardPayment(cost) => {
-                mutation_helper::mixed_payment(game, *cost, Steel, STEEL_VALUE)?
+                mutation_helper::mixed_payment(game, *cost, Steel, STEEL_VALUE, outcome)?
             }
             Mutation::SpaceCardPayment(cost) => {
-                mutation_helper::mixed_payment(game, *cost, Titanium, TITANIUM_VALUE)?
+                mutation_helper::mixed_payment(game, *cost, Titanium, TITANIUM_VALUE, outcome)?
+            }
+            Mutation::MilestoneClaim(milestone) => {
+                mutation_helper::claim_milestone(game, *milestone, outcome)?
             }
-            Mutation::Pass => mutation_helper::pass(game),
+            Mutation::AwardFunding(award) => mutation_helper::fund_award(game, *award, outcome)?,
+            Mutation::ColonyBuild(tile) => mutation_helper::build_colony(game, *tile, outcome)?,
+            Mutation::Trade(tile) => mutation_helper::trade(game, *tile, outcome)?,
+            Mutation::DelegatePlacement(party) => mutation_helper::send_delegate(game, *party)?,
+            Mutation::Pass => mutation_helper::pass(game, outcome)?,
         }
         Ok(())
     }
@@ -104,11 +147,29 @@ impl Display for Mutation {
                 write!(f, "Increase temperature {} step(s)", amount)?
             }
             Mutation::OxygenIncrease(amount) => write!(f, "Increase oxygen {} step(s)", amount)?,
-            Mutation::VictoryPoint(amount) => write!(f, "{} victory point(s)", amount)?,
+            Mutation::VenusIncrease(amount) => write!(f, "Increase Venus {} step(s)", amount)?,
             Mutation::TilePlacement(tile) => write!(f, "Place tile: {:?}", tile)?,
+            Mutation::TilePlacementAt(tile, position) => {
+                write!(f, "Place tile: {:?} at {:?}", tile, position)?
+            }
+            Mutation::RequirementTolerance(steps) => {
+                write!(f, "Global requirements are +/- {} step(s) for you", steps)?
+            }
             Mutation::Tag(tag) => write!(f, "{:?} Tag", tag)?,
             Mutation::CardDraw(amount) => write!(f, "Draw {} cards", amount)?,
             Mutation::CardPlay(card_id) => write!(f, "Card #{}", *card_id)?,
+            Mutation::CardResource(card_id, resource, delta) => write!(
+                f,
+                "{} {:?} resource(s) on card #{}",
+                delta, resource, *card_id
+            )?,
+            Mutation::CardDiscard(card_id) => write!(f, "Discard card #{}", *card_id)?,
+            Mutation::PreludePlay(card_id) => write!(f, "Prelude #{}", *card_id)?,
+            Mutation::MilestoneClaim(milestone) => write!(f, "Claim {} milestone", milestone)?,
+            Mutation::AwardFunding(award) => write!(f, "Fund {} award", award)?,
+            Mutation::ColonyBuild(tile) => write!(f, "Build a colony on {}", tile)?,
+            Mutation::Trade(tile) => write!(f, "Trade with {}", tile)?,
+            Mutation::DelegatePlacement(party) => write!(f, "Send a delegate to {}", party)?,
             Mutation::Pass => write!(f, "Pass")?,
         }
         Ok(())
@@ -118,23 +179,34 @@ impl Display for Mutation {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::model::card::card_compendium::CARD_COMPENDIUM;
+    use crate::model::game::award::AWARD_COSTS;
+    use crate::model::game::board::game_map::NOCTIS_CITY_POSITION;
+    use crate::model::game::colony::Colony;
+    use crate::model::game::milestone::MILESTONE_COST;
+    use crate::model::game::phase::Phase;
+    use crate::model::game::rule_set::RuleSet;
+    use crate::model::game::turmoil::{Turmoil, REDS_TR_INCREASE_COST};
     use crate::model::game::{
-        INITIAL_MEGA_CREDITS, INITIAL_PRODUCTION, INITIAL_TR, MAX_OXYGEN, MAX_TEMPERATURE,
+        INITIAL_MEGA_CREDITS, INITIAL_PRODUCTION, INITIAL_TR, LAST_GENERATION, MAX_OXYGEN,
+        MAX_TEMPERATURE, SOLO_PLAYER,
     };
     use crate::model::resource::Resource;
     use crate::model::tag::Tag::Power;
+    use crate::model::tag::TAGS;
     use crate::THARSIS;
     use rstest::{fixture, rstest};
+    use std::collections::HashSet;
 
     impl Game {
-        fn apply(&mut self, mutation: &Mutation) -> ActionResult {
+        fn apply(&mut self, mutation: &Mutation) -> ActionOutcome {
             mutation.apply(self)
         }
     }
 
     #[fixture]
     fn game() -> Game {
-        let mut game = Game::new(&THARSIS);
+        let mut game = Game::new(&THARSIS, RuleSet::default());
         game.cards_to_be_drawn.sort_unstable();
         game
     }
@@ -236,6 +308,18 @@ mod tests {
         assert_eq!(game.oxygen, MAX_OXYGEN);
     }
 
+    #[rstest]
+    fn test_venus_increase_mutation(mut game: Game) {
+        assert!(game.apply(&Mutation::VenusIncrease(1)).is_err());
+
+        game.rules.venus_next = true;
+        let previous_tr = game.tr;
+        game.venus = game.rules.max_venus - 4;
+        assert!(game.apply(&Mutation::VenusIncrease(3)).is_ok());
+        assert_eq!(game.venus, game.rules.max_venus);
+        assert_eq!(game.tr, previous_tr + 2);
+    }
+
     #[rstest]
     fn test_tag_mutation(mut game: Game) {
         let tag_count = *game.tags.get(&Power).unwrap();
@@ -243,6 +327,15 @@ mod tests {
         assert_eq!(tag_count + 1, *game.tags.get(&Power).unwrap());
     }
 
+    #[rstest]
+    fn test_every_tag_can_be_counted(mut game: Game) {
+        for tag in TAGS.iter() {
+            assert_eq!(game.tag(*tag), 0);
+            assert!(game.apply(&Mutation::Tag(*tag)).is_ok());
+            assert_eq!(game.tag(*tag), 1);
+        }
+    }
+
     #[rstest]
     fn test_card_draw(mut game: Game) {
         let top_card = *game.cards_to_be_drawn.last().unwrap();
@@ -251,6 +344,32 @@ mod tests {
         assert!(!game.cards_to_be_drawn.contains(&top_card));
     }
 
+    #[rstest]
+    fn test_discard_pile_is_reshuffled_when_the_deck_runs_out(mut game: Game) {
+        game.discarded_cards = game.cards_to_be_drawn.split_off(2);
+        let discarded_cards = game.discarded_cards.clone();
+
+        assert!(game
+            .apply(&Mutation::Composite(vec![
+                Mutation::CardDraw(3),
+                Mutation::Resource(MegaCredit, -1000),
+            ]))
+            .is_err());
+        assert_eq!(game.cards_to_be_drawn.len(), 2);
+        assert_eq!(game.discarded_cards, discarded_cards);
+
+        let outcome = game.apply(&Mutation::CardDraw(3)).unwrap();
+        assert_eq!(outcome.drawn_cards().len(), 3);
+        assert_eq!(outcome.undrawn_cards(), 0);
+        assert!(game.discarded_cards.is_empty());
+        assert_eq!(game.cards_to_be_drawn.len(), discarded_cards.len() - 1);
+
+        let deck_size = game.cards_to_be_drawn.len() as i32;
+        let outcome = game.apply(&Mutation::CardDraw(deck_size + 2)).unwrap();
+        assert_eq!(outcome.drawn_cards().len() as i32, deck_size);
+        assert_eq!(outcome.undrawn_cards(), 2);
+    }
+
     #[rstest]
     fn test_card_play(mut game: Game) {
         let card_id = *game.cards_in_hand.iter().take(1).last().unwrap();
@@ -261,22 +380,293 @@ mod tests {
         assert!(game.apply(&Mutation::CardPlay(24353453)).is_err());
     }
 
+    #[rstest]
+    fn test_card_resources(mut game: Game) {
+        let card_id = *game.cards_in_hand.iter().next().unwrap();
+        let add_microbes = |amount| Mutation::CardResource(card_id, CardResource::Microbe, amount);
+        assert!(game.apply(&add_microbes(1)).is_err());
+
+        assert!(game.apply(&Mutation::CardPlay(card_id)).is_ok());
+        assert!(game.apply(&add_microbes(3)).is_ok());
+        assert!(game.apply(&add_microbes(-1)).is_ok());
+        assert_eq!(game.resources_on_card(card_id), 2);
+        assert_eq!(game.card_resources(CardResource::Microbe), 2);
+        assert_eq!(game.card_resources(CardResource::Animal), 0);
+
+        assert!(game.apply(&add_microbes(-3)).is_err());
+        assert!(game
+            .apply(&Mutation::CardResource(card_id, CardResource::Animal, 1))
+            .is_err());
+        assert_eq!(game.resources_on_card(card_id), 2);
+    }
+
+    #[rstest]
+    fn test_card_discard(mut game: Game) {
+        let card_id = *game.cards_in_hand.iter().next().unwrap();
+        assert!(game.apply(&Mutation::CardDiscard(card_id)).is_ok());
+        assert!(!game.cards_in_hand.contains(&card_id));
+        assert_eq!(game.discarded_cards, vec![card_id]);
+
+        assert!(game.apply(&Mutation::CardDiscard(card_id)).is_err());
+        assert_eq!(game.discarded_cards, vec![card_id]);
+    }
+
+    #[rstest]
+    fn test_prelude_play(mut game: Game) {
+        game.phase = Phase::Prelude;
+        game.preludes_in_hand = HashSet::from([1, 2, 3, 4]);
+
+        assert!(game.apply(&Mutation::PreludePlay(5)).is_err());
+        assert!(game.apply(&Mutation::PreludePlay(1)).is_ok());
+        assert_eq!(game.phase, Phase::Prelude);
+        assert!(game.apply(&Mutation::PreludePlay(1)).is_err());
+
+        assert!(game.apply(&Mutation::PreludePlay(2)).is_ok());
+        assert_eq!(game.phase, Phase::Generation);
+        assert_eq!(game.played_preludes, HashSet::from([1, 2]));
+        assert!(game.preludes_in_hand.is_empty());
+    }
+
+    #[rstest]
+    fn test_colonies(mut game: Game) {
+        assert!(game.apply(&Mutation::Trade(ColonyTile::Luna)).is_err());
+
+        game.rules.colonies = true;
+        game.colonies = vec![Colony::new(ColonyTile::Luna)];
+        assert!(game.apply(&Mutation::Trade(ColonyTile::Io)).is_err());
+
+        assert!(game.apply(&Mutation::ColonyBuild(ColonyTile::Luna)).is_ok());
+        assert_eq!(game.production(&MegaCredit), INITIAL_PRODUCTION + 2);
+        assert!(game
+            .apply(&Mutation::ColonyBuild(ColonyTile::Luna))
+            .is_err());
+        assert_eq!(game.colonies[0].colonies(), 1);
+
+        let prev_mega_credits = game.resource(&MegaCredit);
+        assert!(game.apply(&Mutation::Trade(ColonyTile::Luna)).is_ok());
+        assert_eq!(game.resource(&MegaCredit), prev_mega_credits + 2 + 2);
+        assert_eq!(game.available_trade_fleets(), 0);
+        assert!(game.apply(&Mutation::Trade(ColonyTile::Luna)).is_err());
+
+        assert!(game.apply(&Mutation::Pass).is_ok());
+        assert_eq!(game.available_trade_fleets(), 1);
+        assert_eq!(game.colonies[0].track_position(), 2);
+    }
+
+    #[rstest]
+    fn test_reds_charge_for_tr_increases(mut game: Game) {
+        assert!(game
+            .apply(&Mutation::DelegatePlacement(Party::Reds))
+            .is_err());
+
+        game.turmoil = Some(Turmoil::new(0));
+        for _ in 0..3 {
+            assert!(game
+                .apply(&Mutation::DelegatePlacement(Party::Reds))
+                .is_ok());
+        }
+        game.turmoil.as_mut().unwrap().end_generation();
+        assert_eq!(game.turmoil().unwrap().ruling_party(), Party::Reds);
+
+        let prev_mega_credits = game.resource(&MegaCredit);
+        assert!(game.apply(&Mutation::TemperatureIncrease(2)).is_ok());
+        assert_eq!(
+            game.resource(&MegaCredit),
+            prev_mega_credits - 2 * REDS_TR_INCREASE_COST
+        );
+
+        game.resources.insert(MegaCredit, REDS_TR_INCREASE_COST - 1);
+        let prev_tr = game.tr;
+        assert!(game.apply(&Mutation::TR(1)).is_err());
+        assert_eq!(game.tr, prev_tr);
+    }
+
+    #[rstest]
+    fn test_ruling_party_bonus_needs_a_placed_tile(mut game: Game) {
+        game.turmoil = Some(Turmoil::new(0));
+        for _ in 0..3 {
+            assert!(game
+                .apply(&Mutation::DelegatePlacement(Party::MarsFirst))
+                .is_ok());
+        }
+        game.turmoil.as_mut().unwrap().end_generation();
+        assert_eq!(game.turmoil().unwrap().ruling_party(), Party::MarsFirst);
+
+        assert!(game.apply(&Mutation::TilePlacement(Tile::City)).is_ok());
+        assert_eq!(game.resource(&Steel), 1);
+
+        while game.board.place_tile(Tile::City, SOLO_PLAYER).is_some() {}
+        let outcome = game.apply(&Mutation::TilePlacement(Tile::City)).unwrap();
+        assert!(outcome.placed_tiles().is_empty());
+        assert_eq!(game.resource(&Steel), 1);
+    }
+
+    #[rstest]
+    fn test_noctis_city_is_placed_on_its_reserved_position(mut game: Game) {
+        game.cards_in_hand.insert(17);
+        assert!(CARD_COMPENDIUM[&17].play(&mut game).is_ok());
+        assert_eq!(game.board.tile_at(NOCTIS_CITY_POSITION), Some(Tile::City));
+        assert_eq!(game.board.owned_tile_count(Tile::City, SOLO_PLAYER), 1);
+
+        assert!(game
+            .apply(&Mutation::TilePlacementAt(Tile::City, NOCTIS_CITY_POSITION))
+            .is_err());
+    }
+
+    #[rstest]
+    fn test_oceans_beyond_the_maximum_are_skipped(mut game: Game) {
+        game.rules.max_oceans = 2;
+        let outcome = game
+            .apply(&Mutation::Composite(vec![
+                Mutation::TilePlacement(Tile::Ocean),
+                Mutation::TilePlacement(Tile::Ocean),
+                Mutation::TilePlacement(Tile::Ocean),
+            ]))
+            .unwrap();
+        assert_eq!(game.oceans, 2);
+        assert_eq!(outcome.skipped_oceans(), 1);
+
+        let outcome = game.apply(&Mutation::TilePlacement(Tile::Ocean)).unwrap();
+        assert_eq!(outcome.skipped_oceans(), 1);
+        assert_eq!(game.board.tile_count(Tile::Ocean), 2);
+    }
+
+    #[rstest]
+    fn test_outcome_reports_what_changed(mut game: Game) {
+        game.temperature = MAX_TEMPERATURE - 4;
+        let top_cards: Vec<CardId> = game
+            .cards_to_be_drawn
+            .iter()
+            .rev()
+            .take(2)
+            .copied()
+            .collect();
+        let outcome = game
+            .apply(&Mutation::Composite(vec![
+                Mutation::TemperatureIncrease(3),
+                Mutation::TilePlacement(Tile::City),
+                Mutation::Resource(Steel, 2),
+                Mutation::Resource(MegaCredit, -3),
+                Mutation::Production(Plant, 1),
+                Mutation::CardDraw(2),
+            ]))
+            .unwrap();
+
+        assert_eq!(outcome.tr(), 2);
+        assert_eq!(outcome.temperature_steps(), 2);
+        assert_eq!(outcome.capped_steps(), 1);
+        assert_eq!(outcome.placed_tiles().len(), 1);
+        let (tile, position) = outcome.placed_tiles()[0];
+        assert_eq!(tile, Tile::City);
+        assert_eq!(game.board.tile_at(position), Some(Tile::City));
+        assert_eq!(outcome.resource(&Steel), 2);
+        assert_eq!(outcome.resource(&MegaCredit), -3);
+        assert_eq!(outcome.resource(&Titanium), 0);
+        assert_eq!(outcome.production(&Plant), 1);
+        assert_eq!(outcome.drawn_cards(), top_cards.as_slice());
+
+        let outcome = game.apply(&Mutation::BuilderCardPayment(10)).unwrap();
+        assert_eq!(outcome.resource(&Steel), -2);
+        assert_eq!(outcome.resource(&MegaCredit), -6);
+    }
+
+    #[rstest]
+    fn test_invalid_mutation_is_rolled_back() {
+        let mut game = Game::new(
+            &THARSIS,
+            RuleSet {
+                venus_next: true,
+                colonies: true,
+                turmoil: true,
+                preludes: true,
+                ..RuleSet::default()
+            },
+        );
+        let original = game.clone();
+        let mut cards_in_hand = game.cards_in_hand.iter().copied();
+        let (played_card, discarded_card) =
+            (cards_in_hand.next().unwrap(), cards_in_hand.next().unwrap());
+        let colony_tile = game.colonies[0].tile();
+        let preludes: Vec<CardId> = game.preludes_in_hand.iter().copied().collect();
+
+        let mut mutations = vec![
+            Mutation::TR(1),
+            Mutation::TemperatureIncrease(1),
+            Mutation::OxygenIncrease(1),
+            Mutation::VenusIncrease(1),
+            Mutation::TilePlacement(Tile::Ocean),
+            Mutation::TilePlacement(Tile::City),
+            Mutation::Production(Plant, 2),
+            Mutation::Tag(Power),
+            Mutation::RequirementTolerance(2),
+            Mutation::CardPlay(played_card),
+            Mutation::CardResource(played_card, CardResource::Animal, 2),
+            Mutation::CardDiscard(discarded_card),
+            Mutation::PreludePlay(preludes[0]),
+            Mutation::PreludePlay(preludes[1]),
+            Mutation::AwardFunding(Award::Thermalist),
+            Mutation::ColonyBuild(colony_tile),
+            Mutation::Trade(colony_tile),
+            Mutation::DelegatePlacement(Party::Scientists),
+            Mutation::Pass,
+        ];
+        assert!(game
+            .clone()
+            .apply(&Mutation::Composite(mutations.clone()))
+            .is_ok());
+
+        mutations.push(Mutation::Resource(MegaCredit, -1000));
+        assert!(game.apply(&Mutation::Composite(mutations)).is_err());
+
+        assert_eq!(game.generation, original.generation);
+        assert_eq!(game.phase, original.phase);
+        assert_eq!(game.tr, original.tr);
+        assert_eq!(game.temperature, original.temperature);
+        assert_eq!(game.oxygen, original.oxygen);
+        assert_eq!(game.venus, original.venus);
+        assert_eq!(game.oceans, original.oceans);
+        assert_eq!(game.resources, original.resources);
+        assert_eq!(game.productions, original.productions);
+        assert_eq!(game.board.to_string(), original.board.to_string());
+        assert_eq!(game.tags, original.tags);
+        assert_eq!(game.requirement_tolerance, original.requirement_tolerance);
+        assert_eq!(game.cards_in_hand, original.cards_in_hand);
+        assert_eq!(game.played_cards, original.played_cards);
+        assert_eq!(game.card_resources, original.card_resources);
+        assert_eq!(game.cards_to_be_drawn, original.cards_to_be_drawn);
+        assert_eq!(game.discarded_cards, original.discarded_cards);
+        assert_eq!(game.preludes_in_hand, original.preludes_in_hand);
+        assert_eq!(game.played_preludes, original.played_preludes);
+        assert_eq!(game.funded_awards, original.funded_awards);
+        assert_eq!(
+            format!("{:?}", game.colonies),
+            format!("{:?}", original.colonies)
+        );
+        assert_eq!(game.trade_fleets_used, original.trade_fleets_used);
+        assert_eq!(
+            game.turmoil.as_ref().unwrap().to_string(),
+            original.turmoil.as_ref().unwrap().to_string()
+        );
+        assert!(game.journal.is_empty());
+    }
+
     #[rstest]
     fn test_tr_mutation(mut game: Game) {
         let prev_tr = game.tr;
-        let prev_vp = game.victory_points;
+        let prev_vp = game.victory_points();
 
         assert!(game.apply(&Mutation::TR(2)).is_ok());
         assert_eq!(game.tr, prev_tr + 2);
-        assert_eq!(game.victory_points, prev_vp + 2);
+        assert_eq!(game.victory_points(), prev_vp + 2);
     }
 
     #[rstest]
-    fn test_vp_mutation(mut game: Game) {
-        let prev_vp = game.victory_points;
+    fn test_card_victory_points_count_once_played(mut game: Game) {
+        let prev_vp = game.victory_points();
 
-        assert!(game.apply(&Mutation::VictoryPoint(2)).is_ok());
-        assert_eq!(game.victory_points, prev_vp + 2);
+        game.cards_in_hand.insert(1);
+        assert!(game.apply(&Mutation::CardPlay(1)).is_ok());
+        assert_eq!(game.victory_points(), prev_vp + 2);
     }
 
     #[rstest]
@@ -307,4 +697,92 @@ mod tests {
         assert_eq!(*game.resource_mut(&Energy), 3);
         assert_eq!(*game.resource_mut(&Heat), 3);
     }
+
+    #[rstest]
+    fn test_pass_after_last_generation(mut game: Game) {
+        game.generation = LAST_GENERATION;
+        assert!(game.apply(&Mutation::Pass).is_ok());
+        assert_eq!(game.phase, Phase::FinalGreeneryConversion);
+        assert!(!game.is_over());
+
+        let plants = *game.resource_mut(&Plant);
+        assert!(game.apply(&Mutation::Pass).is_ok());
+        assert_eq!(game.phase, Phase::Over);
+        assert_eq!(*game.resource_mut(&Plant), plants);
+        assert!(game.apply(&Mutation::Pass).is_err());
+    }
+
+    #[rstest]
+    fn test_milestone_claim(mut game: Game) {
+        assert!(game
+            .apply(&Mutation::MilestoneClaim(Milestone::Terraformer))
+            .is_err());
+
+        game.tr = 35;
+        game.cards_in_hand.extend(1000..1016);
+        assert!(game
+            .apply(&Mutation::MilestoneClaim(Milestone::Terraformer))
+            .is_ok());
+        assert_eq!(
+            *game.resource_mut(&MegaCredit),
+            INITIAL_MEGA_CREDITS - MILESTONE_COST
+        );
+        assert!(game
+            .apply(&Mutation::MilestoneClaim(Milestone::Terraformer))
+            .is_err());
+
+        *game.resource_mut(&MegaCredit) = MILESTONE_COST - 1;
+        assert!(game
+            .apply(&Mutation::MilestoneClaim(Milestone::Planner))
+            .is_err());
+        *game.resource_mut(&MegaCredit) = 3 * MILESTONE_COST;
+        assert!(game
+            .apply(&Mutation::MilestoneClaim(Milestone::Planner))
+            .is_ok());
+
+        *game.tags.get_mut(&Tag::Builder).unwrap() = 8;
+        assert!(game
+            .apply(&Mutation::MilestoneClaim(Milestone::Builder))
+            .is_ok());
+        assert_eq!(game.score_breakdown().milestones(), 15);
+
+        assert!(game
+            .apply(&Mutation::Composite(vec![
+                Mutation::TilePlacement(Tile::Greenery),
+                Mutation::TilePlacement(Tile::Greenery),
+                Mutation::TilePlacement(Tile::Greenery),
+                Mutation::MilestoneClaim(Milestone::Gardener),
+            ]))
+            .is_err());
+        assert_eq!(game.claimed_milestones().len(), 3);
+    }
+
+    #[rstest]
+    fn test_award_funding(mut game: Game) {
+        *game.resource_mut(&MegaCredit) = AWARD_COSTS.iter().sum::<i32>() + 30;
+
+        assert!(game.apply(&Mutation::AwardFunding(Award::Banker)).is_ok());
+        assert!(game.apply(&Mutation::AwardFunding(Award::Banker)).is_err());
+        assert!(game
+            .apply(&Mutation::AwardFunding(Award::Thermalist))
+            .is_ok());
+        assert!(game
+            .apply(&Mutation::AwardFunding(Award::Scientist))
+            .is_ok());
+        assert_eq!(*game.resource_mut(&MegaCredit), 30);
+        assert!(game.apply(&Mutation::AwardFunding(Award::Miner)).is_err());
+
+        let rules = game.rules;
+        assert_eq!(game.score_breakdown().awards(), 0);
+        *game.productions.get_mut(&MegaCredit).unwrap() = rules.banker_threshold - 1;
+        assert_eq!(game.score_breakdown().awards(), 0);
+        *game.productions.get_mut(&MegaCredit).unwrap() = rules.banker_threshold;
+        assert_eq!(game.score_breakdown().awards(), 5);
+        *game.resource_mut(&Heat) = Award::Thermalist.solo_threshold(&rules);
+        *game.tags.get_mut(&Tag::Science).unwrap() = Award::Scientist.solo_threshold(&rules);
+        assert_eq!(game.score_breakdown().awards(), 15);
+
+        game.rules.scientist_threshold += 1;
+        assert_eq!(game.score_breakdown().awards(), 10);
+    }
 }
@@ -0,0 +1,154 @@
+use std::collections::HashSet;
+
+use rand::rngs::StdRng;
+
+use crate::model::card::CardId;
+use crate::model::game::board::BoardPosition;
+use crate::model::game::colony::Colony;
+use crate::model::game::phase::Phase;
+use crate::model::game::turmoil::Turmoil;
+use crate::model::game::Game;
+use crate::model::resource::{CardResource, Resource};
+use crate::model::tag::Tag;
+
+/**
+A primitive change to the game, holding what is needed to take it back
+*/
+#[derive(Clone, Debug)]
+pub(crate) enum Change {
+    Generation(i32),
+    Phase(Phase),
+    Tr(i32),
+    Oxygen(i32),
+    Temperature(i32),
+    Oceans(i32),
+    Venus(i32),
+    Resource(Resource, i32),
+    Production(Resource, i32),
+    TilePlacement(BoardPosition),
+    Tag(Tag),
+    RequirementTolerance(i32),
+    CardDraw(CardId),
+    DiscardPileReshuffle {
+        discarded_cards: Vec<CardId>,
+        rng: Box<StdRng>,
+    },
+    CardPlay(CardId),
+    CardResources(CardId, Option<(CardResource, i32)>),
+    CardDiscard(CardId),
+    PreludePlay(CardId),
+    PreludesInHand(HashSet<CardId>),
+    MilestoneClaim,
+    AwardFunding,
+    Colonies(Vec<Colony>),
+    TradeFleetsUsed(i32),
+    Turmoil(Box<Turmoil>),
+}
+
+/**
+The changes made by the mutation being applied, so that an invalid mutation can be rolled back
+without cloning the whole game up front.
+Deltas are recorded for counters, the previous value for anything that is replaced.
+*/
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Journal {
+    changes: Vec<Change>,
+    /**
+    Set while the caller rolls back by other means, see `Action::execute_with_snapshot`
+    */
+    paused: bool,
+}
+
+impl Journal {
+    pub fn record(&mut self, change: Change) {
+        if !self.paused {
+            self.changes.push(change);
+        }
+    }
+
+    pub fn set_paused(&mut self, paused: bool) {
+        self.paused = paused;
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.changes.clear();
+    }
+
+    /**
+    Takes back the recorded changes, latest first
+    */
+    pub fn roll_back(game: &mut Game) {
+        while let Some(change) = game.journal.changes.pop() {
+            change.undo(game);
+        }
+    }
+}
+
+impl Change {
+    fn undo(self, game: &mut Game) {
+        match self {
+            Change::Generation(delta) => game.generation -= delta,
+            Change::Phase(phase) => game.phase = phase,
+            Change::Tr(delta) => game.tr -= delta,
+            Change::Oxygen(delta) => game.oxygen -= delta,
+            Change::Temperature(delta) => game.temperature -= delta,
+            Change::Oceans(delta) => game.oceans -= delta,
+            Change::Venus(delta) => game.venus -= delta,
+            Change::Resource(resource, delta) => *game.resource_mut(&resource) -= delta,
+            Change::Production(resource, delta) => {
+                *game.productions.get_mut(&resource).unwrap() -= delta
+            }
+            Change::TilePlacement(position) => game.board.remove_tile(position),
+            Change::Tag(tag) => *game.tags.get_mut(&tag).unwrap() -= 1,
+            Change::RequirementTolerance(delta) => game.requirement_tolerance -= delta,
+            Change::CardDraw(card_id) => {
+                game.cards_in_hand.remove(&card_id);
+                game.cards_to_be_drawn.push(card_id);
+            }
+            Change::DiscardPileReshuffle {
+                discarded_cards,
+                rng,
+            } => {
+                game.cards_to_be_drawn.clear();
+                game.discarded_cards = discarded_cards;
+                game.rng = *rng;
+            }
+            Change::CardPlay(card_id) => {
+                game.played_cards.remove(&card_id);
+                game.cards_in_hand.insert(card_id);
+            }
+            Change::CardResources(card_id, held) => match held {
+                Some(held) => {
+                    game.card_resources.insert(card_id, held);
+                }
+                None => {
+                    game.card_resources.remove(&card_id);
+                }
+            },
+            Change::CardDiscard(card_id) => {
+                game.discarded_cards.pop();
+                game.cards_in_hand.insert(card_id);
+            }
+            Change::PreludePlay(card_id) => {
+                game.played_preludes.remove(&card_id);
+                game.preludes_in_hand.insert(card_id);
+            }
+            Change::PreludesInHand(preludes) => game.preludes_in_hand = preludes,
+            Change::MilestoneClaim => {
+                game.claimed_milestones.pop();
+            }
+            Change::AwardFunding => {
+                game.funded_awards.pop();
+            }
+            Change::Colonies(colonies) => game.colonies = colonies,
+            Change::TradeFleetsUsed(trade_fleets_used) => {
+                game.trade_fleets_used = trade_fleets_used
+            }
+            Change::Turmoil(turmoil) => game.turmoil = Some(*turmoil),
+        }
+    }
+}
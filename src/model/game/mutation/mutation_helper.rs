@@ -1,12 +1,21 @@
 use crate::action::invalid_action::{ActionResult, InvalidActionError};
 use crate::model::card::CardId;
+use crate::model::game::award::{Award, AWARD_COSTS};
 use crate::model::game::board::tile::Tile;
+use crate::model::game::board::BoardPosition;
+use crate::model::game::colony::{Colony, ColonyTile};
+use crate::model::game::milestone::{Milestone, MAX_CLAIMED_MILESTONES, MILESTONE_COST};
+use crate::model::game::mutation::journal::Change;
+use crate::model::game::outcome::Outcome;
+use crate::model::game::phase::Phase;
+use crate::model::game::turmoil::Party;
 use crate::model::game::{
-    Game, MAX_OCEANS, MAX_OXYGEN, MAX_TEMPERATURE, MINIMUM_MEGA_CREDITS_PRODUCTION,
-    MINIMUM_PRODUCTION_OF_NON_MEGA_CREDIT_RESOURCES,
+    Game, MINIMUM_MEGA_CREDITS_PRODUCTION, MINIMUM_PRODUCTION_OF_NON_MEGA_CREDIT_RESOURCES,
+    PRELUDES_TO_PLAY, SOLO_PLAYER, TEMPERATURE_STEP, VENUS_STEP,
 };
-use crate::model::resource::Resource;
 use crate::model::resource::Resource::*;
+use crate::model::resource::{CardResource, Resource};
+use rand::seq::SliceRandom;
 use std::cmp::{max, min};
 
 pub fn minimum_production_value_of(resource: &Resource) -> i32 {
@@ -16,25 +25,60 @@ pub fn minimum_production_value_of(resource: &Resource) -> i32 {
     }
 }
 
-pub fn draw_cards(game: &mut Game, count: i32) {
+/**
+Reshuffles the discard pile when the draw deck runs out.
+Cards that cannot be drawn at all, because both are empty, are reported in the outcome.
+*/
+pub fn draw_cards(game: &mut Game, count: i32, outcome: &mut Outcome) {
     for _ in 0..count {
-        let card_id = game
-            .cards_to_be_drawn
-            .pop()
-            .expect("The draw deck shouldn't run out");
+        if game.cards_to_be_drawn.is_empty() {
+            reshuffle_discard_pile(game);
+        }
+        let Some(card_id) = game.cards_to_be_drawn.pop() else {
+            outcome.record_undrawn_card();
+            continue;
+        };
         game.cards_in_hand.insert(card_id);
+        game.journal.record(Change::CardDraw(card_id));
+        outcome.record_card_draw(card_id);
     }
 }
 
-pub fn increase_oxygen_if_not_maxed_out(game: &mut Game, amount: i32) {
-    let amount = max(0, min(amount, MAX_OXYGEN - game.oxygen));
-    game.oxygen += amount;
-    increase_tr(game, amount);
+fn reshuffle_discard_pile(game: &mut Game) {
+    if game.discarded_cards.is_empty() {
+        return;
+    }
+    game.journal.record(Change::DiscardPileReshuffle {
+        discarded_cards: game.discarded_cards.clone(),
+        rng: Box::new(game.rng.clone()),
+    });
+    game.cards_to_be_drawn = std::mem::take(&mut game.discarded_cards);
+    game.cards_to_be_drawn.shuffle(&mut game.rng);
+}
+
+pub fn increase_oxygen_if_not_maxed_out(
+    game: &mut Game,
+    amount: i32,
+    outcome: &mut Outcome,
+) -> ActionResult {
+    let steps = max(0, min(amount, game.rules.max_oxygen - game.oxygen));
+    game.oxygen += steps;
+    game.journal.record(Change::Oxygen(steps));
+    outcome.record_oxygen_steps(steps, amount - steps);
+    increase_tr(game, steps, outcome)
 }
 
-pub fn increase_tr(game: &mut Game, amount: i32) {
+pub fn increase_tr(game: &mut Game, amount: i32, outcome: &mut Outcome) -> ActionResult {
     game.tr += amount;
-    game.victory_points += amount;
+    game.journal.record(Change::Tr(amount));
+    outcome.record_tr(amount);
+    if let Some(turmoil) = &game.turmoil {
+        let cost = turmoil.ruling_party().tr_increase_cost() * max(amount, 0);
+        if cost > 0 {
+            return resource_change(game, &MegaCredit, -cost, outcome);
+        }
+    }
+    Ok(())
 }
 
 pub fn mixed_payment(
@@ -42,17 +86,18 @@ pub fn mixed_payment(
     cost: i32,
     resource: Resource,
     resource_value: i32,
+    outcome: &mut Outcome,
 ) -> ActionResult {
     let optimal_resource_cost = cost / resource_value;
     let resource_amount = *game.resources.get(&resource).unwrap();
 
     let resource_cost = min(optimal_resource_cost, resource_amount);
     let megacredit_cost = cost - resource_cost * resource_value;
-    spend_resource_unchecked(game, &resource, resource_cost);
+    change_resource_unchecked(game, &resource, -resource_cost, outcome);
 
     if *game.resources.get(&MegaCredit).unwrap() < megacredit_cost {
         if *game.resources.get(&resource).unwrap() > 0 {
-            spend_resource_unchecked(game, &resource, 1);
+            change_resource_unchecked(game, &resource, -1, outcome);
         } else {
             return Err(InvalidActionError::new(format!(
                 "Insufficient {:?} and Mega Credits.",
@@ -60,53 +105,163 @@ pub fn mixed_payment(
             )));
         }
     } else {
-        spend_resource_unchecked(game, &MegaCredit, megacredit_cost);
+        change_resource_unchecked(game, &MegaCredit, -megacredit_cost, outcome);
     }
     Ok(())
 }
 
-fn spend_resource_unchecked(game: &mut Game, resource: &Resource, amount: i32) {
-    *game.resources.get_mut(resource).unwrap() -= amount;
+fn change_resource_unchecked(
+    game: &mut Game,
+    resource: &Resource,
+    delta: i32,
+    outcome: &mut Outcome,
+) {
+    *game.resource_mut(resource) += delta;
+    game.journal
+        .record(Change::Resource(resource.clone(), delta));
+    outcome.record_resource(resource, delta);
 }
 
-pub fn pass(game: &mut Game) {
+pub fn pass(game: &mut Game, outcome: &mut Outcome) -> ActionResult {
+    if game.phase == Phase::FinalGreeneryConversion {
+        set_phase(game, Phase::Over);
+        return Ok(());
+    }
+
     game.generation += 1;
+    game.journal.record(Change::Generation(1));
 
-    *game.resource_mut(&MegaCredit) += game.tr;
-    *game.resource_mut(&Heat) += *game.resource_mut(&Energy);
-    *game.resource_mut(&Energy) = 0;
+    change_resource_unchecked(game, &MegaCredit, game.tr, outcome);
+    let energy = game.resource(&Energy);
+    change_resource_unchecked(game, &Heat, energy, outcome);
+    change_resource_unchecked(game, &Energy, -energy, outcome);
     for resource in [MegaCredit, Steel, Titanium, Plant, Energy, Heat] {
-        let production = *game.productions.get(&resource).unwrap();
-        *game.resource_mut(&resource) += production;
+        let production = game.production(&resource);
+        change_resource_unchecked(game, &resource, production, outcome);
+    }
+
+    draw_cards(game, 4, outcome);
+
+    record_colonies(game);
+    for colony in game.colonies.iter_mut() {
+        colony.advance_track();
     }
+    game.journal
+        .record(Change::TradeFleetsUsed(game.trade_fleets_used));
+    game.trade_fleets_used = 0;
 
-    draw_cards(game, 4);
+    if game.generation > game.rules.last_generation {
+        set_phase(game, Phase::FinalGreeneryConversion);
+    } else if let Some(turmoil) = &game.turmoil {
+        turmoil
+            .current_event()
+            .mutation(game, turmoil.influence())
+            .unsafe_apply(game, outcome)?;
+        let turmoil = game.turmoil.as_mut().unwrap();
+        let previous_turmoil = Box::new(turmoil.clone());
+        turmoil.end_generation();
+        game.journal.record(Change::Turmoil(previous_turmoil));
+    }
+    Ok(())
 }
 
-pub fn place_tile_greedily(game: &mut Game, tile: &Tile) {
-    if *tile == Tile::Ocean && game.oceans == MAX_OCEANS {
-        return;
+fn set_phase(game: &mut Game, phase: Phase) {
+    game.journal.record(Change::Phase(game.phase));
+    game.phase = phase;
+}
+
+fn record_colonies(game: &mut Game) {
+    game.journal.record(Change::Colonies(game.colonies.clone()));
+}
+
+/**
+This is the one place where the ocean cap is enforced.
+As the rule set is validated against the map, an ocean below the cap always finds an empty ocean position.
+*/
+pub fn place_tile(game: &mut Game, tile: &Tile, outcome: &mut Outcome) -> ActionResult {
+    if *tile == Tile::Ocean && game.oceans >= game.rules.max_oceans {
+        outcome.skip_ocean();
+        return Ok(());
+    }
+
+    if let Some(position) = game.board.place_tile(*tile, SOLO_PLAYER) {
+        game.journal.record(Change::TilePlacement(position));
+        outcome.record_tile(*tile, position);
+        ruling_party_bonus(game, tile, outcome)?;
+    }
+
+    global_parameter_effects(game, tile, outcome)
+}
+
+/**
+Places the tile on the named position, such as the one reserved for a card
+*/
+pub fn place_tile_at(
+    game: &mut Game,
+    tile: &Tile,
+    position: BoardPosition,
+    outcome: &mut Outcome,
+) -> ActionResult {
+    if *tile == Tile::Ocean && game.oceans >= game.rules.max_oceans {
+        outcome.skip_ocean();
+        return Ok(());
     }
 
-    let earned_victory_points = game.board.place_tile_greedily(*tile);
-    game.victory_points += earned_victory_points;
+    if game
+        .board
+        .place_tile_at(*tile, SOLO_PLAYER, position)
+        .is_none()
+    {
+        return InvalidActionError::new(format!("Cannot place {:?} at {:?}", tile, position))
+            .into_err();
+    }
+    game.journal.record(Change::TilePlacement(position));
+    outcome.record_tile(*tile, position);
+    ruling_party_bonus(game, tile, outcome)?;
+
+    global_parameter_effects(game, tile, outcome)
+}
+
+/**
+Only granted for a tile that was actually placed
+*/
+fn ruling_party_bonus(game: &mut Game, tile: &Tile, outcome: &mut Outcome) -> ActionResult {
+    match game
+        .turmoil
+        .as_ref()
+        .and_then(|turmoil| turmoil.ruling_party().tile_placement_bonus(*tile))
+    {
+        Some(bonus) => bonus.unsafe_apply(game, outcome),
+        None => Ok(()),
+    }
+}
 
+fn global_parameter_effects(game: &mut Game, tile: &Tile, outcome: &mut Outcome) -> ActionResult {
     match tile {
-        Tile::Greenery => increase_oxygen_if_not_maxed_out(game, 1),
+        Tile::Greenery => increase_oxygen_if_not_maxed_out(game, 1, outcome),
         Tile::Ocean => {
             game.oceans += 1;
-            increase_tr(game, 1)
+            game.journal.record(Change::Oceans(1));
+            increase_tr(game, 1, outcome)
         }
-        _ => (),
+        _ => Ok(()),
     }
 }
 
-pub fn production_change(game: &mut Game, resource: &Resource, delta: i32) -> ActionResult {
+pub fn production_change(
+    game: &mut Game,
+    resource: &Resource,
+    delta: i32,
+    outcome: &mut Outcome,
+) -> ActionResult {
     let production_value = game
         .productions
         .get_mut(resource)
         .expect("Production should be initialized in Game struct");
     *production_value += delta;
+    game.journal
+        .record(Change::Production(resource.clone(), delta));
+    outcome.record_production(resource, delta);
     let min_val = minimum_production_value_of(resource);
     if *production_value < min_val {
         InvalidActionError::new(format!(
@@ -119,32 +274,230 @@ pub fn production_change(game: &mut Game, resource: &Resource, delta: i32) -> Ac
     }
 }
 
-pub fn resource_change(game: &mut Game, resource: &Resource, delta: i32) -> ActionResult {
-    let resource_value = game
-        .resources
-        .get_mut(resource)
-        .expect("Resource should be initialized in Game struct");
-    *resource_value += delta;
-    if *resource_value < 0 {
+pub fn resource_change(
+    game: &mut Game,
+    resource: &Resource,
+    delta: i32,
+    outcome: &mut Outcome,
+) -> ActionResult {
+    change_resource_unchecked(game, resource, delta, outcome);
+    if game.resource(resource) < 0 {
         InvalidActionError::new(format!("Not enough {:?} resources", resource)).into_err()
     } else {
         Ok(())
     }
 }
 
-pub fn increase_temperature_if_not_maxed_out(game: &mut Game, mut amount: i32) {
-    while game.temperature < MAX_TEMPERATURE && amount > 0 {
-        game.temperature += 2;
+pub fn increase_temperature_if_not_maxed_out(
+    game: &mut Game,
+    mut amount: i32,
+    outcome: &mut Outcome,
+) -> ActionResult {
+    while game.temperature < game.rules.max_temperature && amount > 0 {
+        game.temperature += TEMPERATURE_STEP;
+        game.journal.record(Change::Temperature(TEMPERATURE_STEP));
+        amount -= 1;
+        outcome.record_temperature_steps(1, 0);
+        increase_tr(game, 1, outcome)?;
+    }
+    outcome.record_temperature_steps(0, max(amount, 0));
+    Ok(())
+}
+
+pub fn increase_venus_if_not_maxed_out(
+    game: &mut Game,
+    mut amount: i32,
+    outcome: &mut Outcome,
+) -> ActionResult {
+    if !game.rules.venus_next {
+        return InvalidActionError::from("Venus Next is not in play").into_err();
+    }
+
+    while game.venus < game.rules.max_venus && amount > 0 {
+        game.venus += VENUS_STEP;
+        game.journal.record(Change::Venus(VENUS_STEP));
         amount -= 1;
-        increase_tr(game, 1);
+        outcome.record_venus_steps(1, 0);
+        increase_tr(game, 1, outcome)?;
     }
+    outcome.record_venus_steps(0, max(amount, 0));
+    Ok(())
 }
 
 pub fn play_card(game: &mut Game, card_id: CardId) -> ActionResult {
     if game.cards_in_hand.remove(&card_id) {
         game.played_cards.insert(card_id);
+        game.journal.record(Change::CardPlay(card_id));
         Ok(())
     } else {
         InvalidActionError::new(format!("Card #{:0>3} not in hand", card_id)).into_err()
     }
 }
+
+/**
+A card holds a single type of resource, and only once it has been played
+*/
+pub fn card_resource_change(
+    game: &mut Game,
+    card_id: CardId,
+    resource: CardResource,
+    delta: i32,
+) -> ActionResult {
+    if !game.played_cards.contains(&card_id) {
+        return InvalidActionError::new(format!("Card #{:0>3} not played", card_id)).into_err();
+    }
+
+    let held = game.card_resources.get(&card_id).copied();
+    let amount = match held {
+        Some((held_resource, _)) if held_resource != resource => {
+            return InvalidActionError::new(format!(
+                "Card #{:0>3} holds {:?} resources, not {:?}",
+                card_id, held_resource, resource
+            ))
+            .into_err()
+        }
+        Some((_, amount)) => amount,
+        None => 0,
+    };
+    if amount + delta < 0 {
+        return InvalidActionError::new(format!(
+            "Not enough {:?} resources on card #{:0>3}",
+            resource, card_id
+        ))
+        .into_err();
+    }
+
+    game.card_resources
+        .insert(card_id, (resource, amount + delta));
+    game.journal.record(Change::CardResources(card_id, held));
+    Ok(())
+}
+
+pub fn play_prelude(game: &mut Game, card_id: CardId) -> ActionResult {
+    if !game.preludes_in_hand.remove(&card_id) {
+        return InvalidActionError::new(format!("Prelude #{:0>2} not in hand", card_id)).into_err();
+    }
+
+    game.played_preludes.insert(card_id);
+    game.journal.record(Change::PreludePlay(card_id));
+    if game.played_preludes.len() == PRELUDES_TO_PLAY {
+        let preludes_in_hand = std::mem::take(&mut game.preludes_in_hand);
+        game.journal
+            .record(Change::PreludesInHand(preludes_in_hand));
+        set_phase(game, Phase::Generation);
+    }
+    Ok(())
+}
+
+pub fn discard_card(game: &mut Game, card_id: CardId) -> ActionResult {
+    if game.cards_in_hand.remove(&card_id) {
+        game.discarded_cards.push(card_id);
+        game.journal.record(Change::CardDiscard(card_id));
+        Ok(())
+    } else {
+        InvalidActionError::new(format!("Card #{:0>3} not in hand", card_id)).into_err()
+    }
+}
+
+pub fn claim_milestone(
+    game: &mut Game,
+    milestone: Milestone,
+    outcome: &mut Outcome,
+) -> ActionResult {
+    if game.claimed_milestones.contains(&milestone) {
+        return InvalidActionError::new(format!("{} milestone already claimed", milestone))
+            .into_err();
+    }
+    if game.claimed_milestones.len() == MAX_CLAIMED_MILESTONES {
+        return InvalidActionError::new(format!(
+            "At most {} milestones can be claimed",
+            MAX_CLAIMED_MILESTONES
+        ))
+        .into_err();
+    }
+    if !milestone.is_reached(game) {
+        return InvalidActionError::new(format!("{} milestone not reached", milestone)).into_err();
+    }
+
+    resource_change(game, &MegaCredit, -MILESTONE_COST, outcome)?;
+    game.claimed_milestones.push(milestone);
+    game.journal.record(Change::MilestoneClaim);
+    Ok(())
+}
+
+pub fn fund_award(game: &mut Game, award: Award, outcome: &mut Outcome) -> ActionResult {
+    if game.funded_awards.contains(&award) {
+        return InvalidActionError::new(format!("{} award already funded", award)).into_err();
+    }
+    let cost = match AWARD_COSTS.get(game.funded_awards.len()) {
+        Some(cost) => *cost,
+        None => {
+            return InvalidActionError::new(format!(
+                "At most {} awards can be funded",
+                AWARD_COSTS.len()
+            ))
+            .into_err()
+        }
+    };
+
+    resource_change(game, &MegaCredit, -cost, outcome)?;
+    game.funded_awards.push(award);
+    game.journal.record(Change::AwardFunding);
+    Ok(())
+}
+
+fn colony_mut(game: &mut Game, tile: ColonyTile) -> Result<&mut Colony, InvalidActionError> {
+    if !game.rules.colonies {
+        return InvalidActionError::from("Colonies are not in play").into_err();
+    }
+    game.colonies
+        .iter_mut()
+        .find(|colony| colony.tile() == tile)
+        .ok_or_else(|| InvalidActionError::new(format!("{} is not in play", tile)))
+}
+
+pub fn build_colony(game: &mut Game, tile: ColonyTile, outcome: &mut Outcome) -> ActionResult {
+    let colony = colony_mut(game, tile)?;
+    if colony.has_colony_of(SOLO_PLAYER) {
+        return InvalidActionError::new(format!("You already have a colony on {}", tile))
+            .into_err();
+    }
+    if colony.is_full() {
+        return InvalidActionError::new(format!("{} has no room left for a colony", tile))
+            .into_err();
+    }
+
+    record_colonies(game);
+    colony_mut(game, tile)?
+        .build(SOLO_PLAYER)
+        .unsafe_apply(game, outcome)
+}
+
+pub fn trade(game: &mut Game, tile: ColonyTile, outcome: &mut Outcome) -> ActionResult {
+    if game.available_trade_fleets() == 0 {
+        return InvalidActionError::from("No trade fleet available").into_err();
+    }
+
+    colony_mut(game, tile)?;
+    record_colonies(game);
+    let income = colony_mut(game, tile)?.trade(SOLO_PLAYER);
+    game.journal
+        .record(Change::TradeFleetsUsed(game.trade_fleets_used));
+    game.trade_fleets_used += 1;
+    income.unsafe_apply(game, outcome)
+}
+
+pub fn send_delegate(game: &mut Game, party: Party) -> ActionResult {
+    match game.turmoil.as_mut() {
+        None => InvalidActionError::from("Turmoil is not in play").into_err(),
+        Some(turmoil) if turmoil.delegate_reserve() == 0 => {
+            InvalidActionError::from("No delegates left in reserve").into_err()
+        }
+        Some(turmoil) => {
+            let previous_turmoil = Box::new(turmoil.clone());
+            turmoil.add_player_delegate(party);
+            game.journal.record(Change::Turmoil(previous_turmoil));
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,179 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+use crate::model::card::CardId;
+use crate::model::game::board::tile::Tile;
+use crate::model::game::board::BoardPosition;
+use crate::model::resource::{Resource, RESOURCES};
+
+/**
+What applying a mutation actually did, including where it fell short of what was asked for
+*/
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Outcome {
+    tr: i32,
+    temperature_steps: i32,
+    oxygen_steps: i32,
+    venus_steps: i32,
+    capped_steps: i32,
+    skipped_oceans: i32,
+    placed_tiles: Vec<(Tile, BoardPosition)>,
+    resources: HashMap<Resource, i32>,
+    productions: HashMap<Resource, i32>,
+    drawn_cards: Vec<CardId>,
+    undrawn_cards: i32,
+}
+
+impl Outcome {
+    /**
+    The net change of the terraform rating
+    */
+    pub fn tr(&self) -> i32 {
+        self.tr
+    }
+
+    pub fn temperature_steps(&self) -> i32 {
+        self.temperature_steps
+    }
+
+    pub fn oxygen_steps(&self) -> i32 {
+        self.oxygen_steps
+    }
+
+    pub fn venus_steps(&self) -> i32 {
+        self.venus_steps
+    }
+
+    /**
+    Temperature, oxygen and Venus steps that were not taken because the parameter was maxed out
+    */
+    pub fn capped_steps(&self) -> i32 {
+        self.capped_steps
+    }
+
+    /**
+    Oceans that were not placed because the maximum number of oceans had been reached
+    */
+    pub fn skipped_oceans(&self) -> i32 {
+        self.skipped_oceans
+    }
+
+    pub fn placed_tiles(&self) -> &[(Tile, BoardPosition)] {
+        &self.placed_tiles
+    }
+
+    /**
+    The net change of the resource, negative if more was spent than gained
+    */
+    pub fn resource(&self, resource: &Resource) -> i32 {
+        self.resources.get(resource).copied().unwrap_or(0)
+    }
+
+    pub fn production(&self, resource: &Resource) -> i32 {
+        self.productions.get(resource).copied().unwrap_or(0)
+    }
+
+    pub fn drawn_cards(&self) -> &[CardId] {
+        &self.drawn_cards
+    }
+
+    /**
+    Cards that were not drawn because both the draw deck and the discard pile were empty
+    */
+    pub fn undrawn_cards(&self) -> i32 {
+        self.undrawn_cards
+    }
+
+    pub(crate) fn record_tr(&mut self, delta: i32) {
+        self.tr += delta;
+    }
+
+    pub(crate) fn record_temperature_steps(&mut self, steps: i32, capped_steps: i32) {
+        self.temperature_steps += steps;
+        self.capped_steps += capped_steps;
+    }
+
+    pub(crate) fn record_oxygen_steps(&mut self, steps: i32, capped_steps: i32) {
+        self.oxygen_steps += steps;
+        self.capped_steps += capped_steps;
+    }
+
+    pub(crate) fn record_venus_steps(&mut self, steps: i32, capped_steps: i32) {
+        self.venus_steps += steps;
+        self.capped_steps += capped_steps;
+    }
+
+    pub(crate) fn skip_ocean(&mut self) {
+        self.skipped_oceans += 1;
+    }
+
+    pub(crate) fn record_tile(&mut self, tile: Tile, position: BoardPosition) {
+        self.placed_tiles.push((tile, position));
+    }
+
+    pub(crate) fn record_resource(&mut self, resource: &Resource, delta: i32) {
+        *self.resources.entry(resource.clone()).or_insert(0) += delta;
+    }
+
+    pub(crate) fn record_production(&mut self, resource: &Resource, delta: i32) {
+        *self.productions.entry(resource.clone()).or_insert(0) += delta;
+    }
+
+    pub(crate) fn record_card_draw(&mut self, card_id: CardId) {
+        self.drawn_cards.push(card_id);
+    }
+
+    pub(crate) fn record_undrawn_card(&mut self) {
+        self.undrawn_cards += 1;
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.tr != 0 {
+            writeln!(f, "TR: {:+}", self.tr)?;
+        }
+        for (name, steps) in [
+            ("Temperature", self.temperature_steps),
+            ("Oxygen", self.oxygen_steps),
+            ("Venus", self.venus_steps),
+        ] {
+            if steps != 0 {
+                writeln!(f, "{}: {:+} step(s)", name, steps)?;
+            }
+        }
+        if self.capped_steps != 0 {
+            writeln!(
+                f,
+                "Maxed out parameters: {} step(s) lost",
+                self.capped_steps
+            )?;
+        }
+        if self.skipped_oceans != 0 {
+            writeln!(f, "Maxed out oceans: {} ocean(s) lost", self.skipped_oceans)?;
+        }
+        for (tile, position) in self.placed_tiles.iter() {
+            writeln!(f, "Placed {:?} at {:?}", tile, position)?;
+        }
+        for resource in RESOURCES.iter() {
+            if self.resource(resource) != 0 {
+                writeln!(f, "{:?}: {:+}", resource, self.resource(resource))?;
+            }
+            if self.production(resource) != 0 {
+                writeln!(
+                    f,
+                    "{:?} production: {:+}",
+                    resource,
+                    self.production(resource)
+                )?;
+            }
+        }
+        if !self.drawn_cards.is_empty() {
+            writeln!(f, "Drawn cards: {:?}", self.drawn_cards)?;
+        }
+        if self.undrawn_cards != 0 {
+            writeln!(f, "Empty deck: {} card(s) not drawn", self.undrawn_cards)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,14 @@
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Phase {
+    /**
+    Precedes the first generation when preludes are in play, until the player has played two of them
+    */
+    Prelude,
+    Generation,
+    /**
+    Follows the production of the last generation. Only plants may be converted into greeneries,
+    until the player passes once more.
+    */
+    FinalGreeneryConversion,
+    Over,
+}
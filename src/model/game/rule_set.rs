@@ -0,0 +1,173 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use crate::model::game::board::game_map::GameMap;
+use crate::model::game::{
+    INITIAL_MEGA_CREDITS, INITIAL_OXYGEN, INITIAL_PRODUCTION, INITIAL_TEMPERATURE, INITIAL_TR,
+    LAST_GENERATION, MAX_OCEANS, MAX_OXYGEN, MAX_TEMPERATURE, MAX_VENUS,
+};
+
+/**
+The tunable parameters of a game. The default is the solo rule set.
+Variants are best built from it, e.g. `RuleSet { last_generation: 10, ..RuleSet::default() }`.
+*/
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RuleSet {
+    pub initial_tr: i32,
+    pub initial_temperature: i32,
+    pub initial_oxygen: i32,
+    pub initial_mega_credits: i32,
+    pub initial_production: i32,
+
+    pub max_temperature: i32,
+    pub max_oxygen: i32,
+    pub max_oceans: i32,
+
+    pub last_generation: i32,
+
+    /// Neutral cities placed at setup, each with an adjacent neutral greenery
+    pub neutral_cities: i32,
+    /// Seeds the placement of the neutral tiles; a random seed is used if `None`
+    pub setup_seed: Option<u64>,
+
+    pub heat_conversion_cost: i32,
+    pub plant_conversion_cost: i32,
+
+    /// Scores needed for first place in an award. The official solo game has no awards,
+    /// so these house rule thresholds stand in for the opponents' scores.
+    pub landlord_threshold: i32,
+    pub banker_threshold: i32,
+    pub scientist_threshold: i32,
+    pub thermalist_threshold: i32,
+    pub miner_threshold: i32,
+
+    pub standard_power_plant_cost: i32,
+    pub standard_asteroid_cost: i32,
+    pub standard_aquifer_cost: i32,
+    pub standard_greenery_cost: i32,
+    pub standard_city_cost: i32,
+
+    /// Adds the Venus scale, which then has to be maxed out as well to win
+    pub venus_next: bool,
+    pub max_venus: i32,
+    pub standard_air_scrapping_cost: i32,
+
+    /// Starts the game with a prelude phase
+    pub preludes: bool,
+
+    /// Adds colony tiles, which can be traded with and built upon
+    pub colonies: bool,
+    pub colony_tiles_in_play: usize,
+    pub trade_fleets: i32,
+    pub trade_mega_credit_cost: i32,
+    pub trade_energy_cost: i32,
+    pub trade_titanium_cost: i32,
+    pub standard_colony_cost: i32,
+
+    /// Adds political parties, whose ruling party's policy applies to the player, and global events
+    pub turmoil: bool,
+    pub delegate_cost: i32,
+}
+
+impl RuleSet {
+    /**
+    Checks that a game with these rules can be played on the map
+    */
+    pub fn validate(&self, map: &GameMap) -> Result<(), InvalidRuleSetError> {
+        let ocean_positions = map.ocean_positions().len() as i32;
+        if ocean_positions < self.max_oceans {
+            return Err(InvalidRuleSetError(format!(
+                "The map has {} ocean positions, but {} oceans can be placed",
+                ocean_positions, self.max_oceans
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl Default for RuleSet {
+    fn default() -> Self {
+        Self {
+            initial_tr: INITIAL_TR,
+            initial_temperature: INITIAL_TEMPERATURE,
+            initial_oxygen: INITIAL_OXYGEN,
+            initial_mega_credits: INITIAL_MEGA_CREDITS,
+            initial_production: INITIAL_PRODUCTION,
+
+            max_temperature: MAX_TEMPERATURE,
+            max_oxygen: MAX_OXYGEN,
+            max_oceans: MAX_OCEANS,
+
+            last_generation: LAST_GENERATION,
+
+            neutral_cities: 2,
+            setup_seed: None,
+
+            heat_conversion_cost: 8,
+            plant_conversion_cost: 8,
+
+            landlord_threshold: 12,
+            banker_threshold: 10,
+            scientist_threshold: 5,
+            thermalist_threshold: 20,
+            miner_threshold: 12,
+
+            standard_power_plant_cost: 11,
+            standard_asteroid_cost: 14,
+            standard_aquifer_cost: 18,
+            standard_greenery_cost: 23,
+            standard_city_cost: 25,
+
+            venus_next: false,
+            max_venus: MAX_VENUS,
+            standard_air_scrapping_cost: 15,
+
+            preludes: false,
+
+            colonies: false,
+            colony_tiles_in_play: 3,
+            trade_fleets: 1,
+            trade_mega_credit_cost: 9,
+            trade_energy_cost: 3,
+            trade_titanium_cost: 3,
+            standard_colony_cost: 17,
+
+            turmoil: false,
+            delegate_cost: 5,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct InvalidRuleSetError(String);
+
+impl Display for InvalidRuleSetError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Error for InvalidRuleSetError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::THARSIS;
+
+    #[test]
+    fn test_max_oceans_must_fit_on_the_map() {
+        assert!(RuleSet::default().validate(&THARSIS).is_ok());
+
+        let rules = RuleSet {
+            max_oceans: THARSIS.ocean_positions().len() as i32,
+            ..RuleSet::default()
+        };
+        assert!(rules.validate(&THARSIS).is_ok());
+
+        let rules = RuleSet {
+            max_oceans: rules.max_oceans + 1,
+            ..rules
+        };
+        assert!(rules.validate(&THARSIS).is_err());
+    }
+}
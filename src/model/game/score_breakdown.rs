@@ -0,0 +1,135 @@
+use std::fmt::{Display, Formatter};
+
+use crate::model::card::CardId;
+
+/**
+Victory points of a game, split up by where they come from
+*/
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScoreBreakdown {
+    tr: i32,
+    card_victory_points: Vec<(CardId, i32)>,
+    greenery_tiles: i32,
+    city_adjacency: i32,
+    milestones: i32,
+    awards: i32,
+}
+
+impl ScoreBreakdown {
+    pub(crate) fn new(
+        tr: i32,
+        card_victory_points: Vec<(CardId, i32)>,
+        greenery_tiles: i32,
+        city_adjacency: i32,
+        milestones: i32,
+        awards: i32,
+    ) -> Self {
+        Self {
+            tr,
+            card_victory_points,
+            greenery_tiles,
+            city_adjacency,
+            milestones,
+            awards,
+        }
+    }
+
+    pub fn tr(&self) -> i32 {
+        self.tr
+    }
+
+    /**
+    Victory points of each played card worth any, in ascending card id order
+    */
+    pub fn card_victory_points(&self) -> &[(CardId, i32)] {
+        &self.card_victory_points
+    }
+
+    pub fn total_card_victory_points(&self) -> i32 {
+        self.card_victory_points.iter().map(|(_, vp)| vp).sum()
+    }
+
+    /**
+    One victory point per greenery tile on the board
+    */
+    pub fn greenery_tiles(&self) -> i32 {
+        self.greenery_tiles
+    }
+
+    /**
+    One victory point per greenery tile adjacent to each city
+    */
+    pub fn city_adjacency(&self) -> i32 {
+        self.city_adjacency
+    }
+
+    pub fn milestones(&self) -> i32 {
+        self.milestones
+    }
+
+    pub fn awards(&self) -> i32 {
+        self.awards
+    }
+
+    pub fn total(&self) -> i32 {
+        self.tr
+            + self.total_card_victory_points()
+            + self.greenery_tiles
+            + self.city_adjacency
+            + self.milestones
+            + self.awards
+    }
+}
+
+impl Display for ScoreBreakdown {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Terraforming Rating:   {}", self.tr)?;
+        writeln!(
+            f,
+            "Cards:                 {}",
+            self.total_card_victory_points()
+        )?;
+        for (card_id, victory_points) in self.card_victory_points.iter() {
+            writeln!(f, "  Card #{:0>3}:           {}", card_id, victory_points)?;
+        }
+        writeln!(f, "Greenery tiles:        {}", self.greenery_tiles)?;
+        writeln!(f, "City adjacency:        {}", self.city_adjacency)?;
+        writeln!(f, "Milestones:            {}", self.milestones)?;
+        writeln!(f, "Awards:                {}", self.awards)?;
+        write!(f, "Total:                 {}", self.total())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::game::board::tile::Tile::{City, Greenery};
+    use crate::model::game::mutation::Mutation;
+    use crate::model::game::rule_set::RuleSet;
+    use crate::model::game::Game;
+    use crate::THARSIS;
+
+    #[test]
+    fn test_breakdown_adds_up_to_victory_points() {
+        let mut game = Game::new(&THARSIS, RuleSet::default());
+        let placements = Mutation::Composite(vec![
+            Mutation::TilePlacement(City),
+            Mutation::TilePlacement(Greenery),
+            Mutation::TilePlacement(Greenery),
+            Mutation::TilePlacement(City),
+            Mutation::TilePlacement(Greenery),
+        ]);
+        assert!(placements.apply(&mut game).is_ok());
+        game.played_cards.extend([1, 16]);
+
+        let breakdown = game.score_breakdown();
+        assert_eq!(breakdown.tr(), game.tr());
+        assert_eq!(breakdown.card_victory_points(), &[(1, 2), (16, 1)]);
+        assert_eq!(breakdown.greenery_tiles(), 3);
+        assert!(breakdown.city_adjacency() >= 3);
+        assert_eq!(
+            breakdown.total(),
+            game.tr() + 3 + 3 + breakdown.city_adjacency()
+        );
+        assert_eq!(breakdown.total(), game.victory_points());
+    }
+}
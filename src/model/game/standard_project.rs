@@ -0,0 +1,205 @@
+use std::fmt::{Display, Formatter};
+
+use crate::model::game::board::tile::Tile;
+use crate::model::game::mutation::Mutation;
+use crate::model::game::rule_set::RuleSet;
+use crate::model::resource::Resource;
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum StandardProjectKind {
+    PowerPlant,
+    Asteroid,
+    Aquifer,
+    Greenery,
+    City,
+    AirScrapping,
+}
+
+pub struct StandardProject {
+    kind: StandardProjectKind,
+    name: &'static str,
+    cost: i32,
+    mutation: Mutation,
+}
+
+impl StandardProject {
+    pub(crate) fn new(
+        kind: StandardProjectKind,
+        name: &'static str,
+        cost: i32,
+        effects: Vec<Mutation>,
+    ) -> Self {
+        let mut mutations = vec![Mutation::Resource(Resource::MegaCredit, -cost)];
+        mutations.extend(effects);
+        Self {
+            kind,
+            name,
+            cost,
+            mutation: Mutation::Composite(mutations),
+        }
+    }
+
+    pub fn kind(&self) -> StandardProjectKind {
+        self.kind
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /**
+    The cost in Mega Credits
+    */
+    pub fn cost(&self) -> i32 {
+        self.cost
+    }
+
+    /**
+    The whole project, payment included
+    */
+    pub(crate) fn mutation(&self) -> &Mutation {
+        &self.mutation
+    }
+}
+
+impl Display for StandardProject {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({} MC)", self.name, self.cost)
+    }
+}
+
+/**
+The standard projects available in a game, in the order they are listed to the player
+*/
+pub struct StandardProjectCatalog {
+    projects: Vec<StandardProject>,
+}
+
+impl StandardProjectCatalog {
+    pub(crate) fn new(rules: &RuleSet) -> Self {
+        let mut catalog = Self {
+            projects: Vec::new(),
+        };
+        for project in [
+            StandardProject::new(
+                StandardProjectKind::PowerPlant,
+                "Power Plant",
+                rules.standard_power_plant_cost,
+                vec![Mutation::Production(Resource::Energy, 1)],
+            ),
+            StandardProject::new(
+                StandardProjectKind::Asteroid,
+                "Asteroid",
+                rules.standard_asteroid_cost,
+                vec![Mutation::TemperatureIncrease(1)],
+            ),
+            StandardProject::new(
+                StandardProjectKind::Aquifer,
+                "Aquifer",
+                rules.standard_aquifer_cost,
+                vec![Mutation::TilePlacement(Tile::Ocean)],
+            ),
+            StandardProject::new(
+                StandardProjectKind::Greenery,
+                "Greenery",
+                rules.standard_greenery_cost,
+                vec![Mutation::TilePlacement(Tile::Greenery)],
+            ),
+            StandardProject::new(
+                StandardProjectKind::City,
+                "City",
+                rules.standard_city_cost,
+                vec![
+                    Mutation::TilePlacement(Tile::City),
+                    Mutation::Production(Resource::MegaCredit, 1),
+                ],
+            ),
+        ] {
+            catalog.register(project);
+        }
+
+        if rules.venus_next {
+            catalog.register(StandardProject::new(
+                StandardProjectKind::AirScrapping,
+                "Air Scrapping",
+                rules.standard_air_scrapping_cost,
+                vec![Mutation::VenusIncrease(1)],
+            ));
+        }
+        catalog
+    }
+
+    /**
+    Adds a project to the catalog, replacing any project of the same kind
+    */
+    pub(crate) fn register(&mut self, project: StandardProject) {
+        match self.projects.iter_mut().find(|p| p.kind == project.kind) {
+            Some(existing_project) => *existing_project = project,
+            None => self.projects.push(project),
+        }
+    }
+
+    pub fn get(&self, kind: StandardProjectKind) -> Option<&StandardProject> {
+        self.projects.iter().find(|project| project.kind == kind)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &StandardProject> {
+        self.projects.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_catalog_uses_rule_set_costs() {
+        let rules = RuleSet {
+            standard_city_cost: 30,
+            ..RuleSet::default()
+        };
+        let catalog = StandardProjectCatalog::new(&rules);
+
+        assert_eq!(catalog.iter().count(), 5);
+        assert!(catalog.get(StandardProjectKind::AirScrapping).is_none());
+        assert_eq!(catalog.get(StandardProjectKind::City).unwrap().cost(), 30);
+        assert_eq!(
+            catalog.get(StandardProjectKind::PowerPlant).unwrap().cost(),
+            rules.standard_power_plant_cost
+        );
+    }
+
+    #[test]
+    fn test_venus_next_adds_air_scrapping() {
+        let rules = RuleSet {
+            venus_next: true,
+            ..RuleSet::default()
+        };
+        let catalog = StandardProjectCatalog::new(&rules);
+
+        assert_eq!(catalog.iter().count(), 6);
+        assert_eq!(
+            catalog
+                .get(StandardProjectKind::AirScrapping)
+                .unwrap()
+                .cost(),
+            rules.standard_air_scrapping_cost
+        );
+    }
+
+    #[test]
+    fn test_register_replaces_projects_of_the_same_kind() {
+        let mut catalog = StandardProjectCatalog::new(&RuleSet::default());
+        catalog.register(StandardProject::new(
+            StandardProjectKind::Asteroid,
+            "Cheap Asteroid",
+            5,
+            vec![Mutation::TemperatureIncrease(1)],
+        ));
+
+        assert_eq!(catalog.iter().count(), 5);
+        let asteroid = catalog.get(StandardProjectKind::Asteroid).unwrap();
+        assert_eq!(asteroid.name(), "Cheap Asteroid");
+        assert_eq!(asteroid.to_string(), "Cheap Asteroid (5 MC)");
+    }
+}
@@ -0,0 +1,424 @@
+use std::cmp::{max, min};
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+
+use crate::model::game::board::tile::Tile;
+use crate::model::game::mutation::Mutation;
+use crate::model::game::{Game, SOLO_PLAYER};
+use crate::model::resource::Resource;
+use crate::model::tag::Tag;
+
+pub const DELEGATES: i32 = 7;
+pub const REDS_TR_INCREASE_COST: i32 = 3;
+const MAX_GLOBAL_EVENT_MULTIPLIER: i32 = 5;
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Party {
+    MarsFirst,
+    Scientists,
+    Unity,
+    Greens,
+    Reds,
+    Kelvinists,
+}
+
+pub const PARTIES: [Party; 6] = [
+    Party::MarsFirst,
+    Party::Scientists,
+    Party::Unity,
+    Party::Greens,
+    Party::Reds,
+    Party::Kelvinists,
+];
+
+impl Party {
+    /**
+    The Mega Credits the ruling party charges for each step of TR increase
+    */
+    pub(crate) fn tr_increase_cost(&self) -> i32 {
+        match self {
+            Self::Reds => REDS_TR_INCREASE_COST,
+            _ => 0,
+        }
+    }
+
+    /**
+    What the ruling party grants for placing a tile.
+    Policies that are actions rather than passive effects are not simulated.
+    */
+    pub(crate) fn tile_placement_bonus(&self, tile: Tile) -> Option<Mutation> {
+        match (self, tile) {
+            (Self::MarsFirst, Tile::Greenery | Tile::City) => {
+                Some(Mutation::Resource(Resource::Steel, 1))
+            }
+            (Self::Greens, Tile::Greenery) => Some(Mutation::Resource(Resource::MegaCredit, 4)),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Party {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl FromStr for Party {
+    type Err = std::fmt::Error;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        PARTIES
+            .into_iter()
+            .find(|party| party.to_string().eq_ignore_ascii_case(name))
+            .ok_or(std::fmt::Error)
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum GlobalEvent {
+    ScienceFunding,
+    AsteroidMining,
+    Pandemic,
+    WarOnEarth,
+    Riots,
+    EcoSabotage,
+}
+
+pub const GLOBAL_EVENTS: [GlobalEvent; 6] = [
+    GlobalEvent::ScienceFunding,
+    GlobalEvent::AsteroidMining,
+    GlobalEvent::Pandemic,
+    GlobalEvent::WarOnEarth,
+    GlobalEvent::Riots,
+    GlobalEvent::EcoSabotage,
+];
+
+impl GlobalEvent {
+    /**
+    The party receiving a neutral delegate when the event comes up
+    */
+    pub fn party(&self) -> Party {
+        match self {
+            Self::ScienceFunding => Party::Scientists,
+            Self::AsteroidMining => Party::Reds,
+            Self::Pandemic => Party::Greens,
+            Self::WarOnEarth => Party::MarsFirst,
+            Self::Riots => Party::Kelvinists,
+            Self::EcoSabotage => Party::Unity,
+        }
+    }
+
+    /**
+    The effect of the event on the player, softened or boosted by the player's influence.
+    Losses are capped at what the player has, so that resolving an event never fails.
+    */
+    pub(crate) fn mutation(&self, game: &Game, influence: i32) -> Mutation {
+        let mega_credit_loss = |loss: i32| {
+            Mutation::Resource(
+                Resource::MegaCredit,
+                -min(max(loss, 0), game.resource(&Resource::MegaCredit)),
+            )
+        };
+        match self {
+            Self::ScienceFunding => Mutation::Resource(
+                Resource::MegaCredit,
+                min(game.tag(Tag::Science), MAX_GLOBAL_EVENT_MULTIPLIER) + influence,
+            ),
+            Self::AsteroidMining => Mutation::Resource(
+                Resource::Titanium,
+                min(game.tag(Tag::Jovian), MAX_GLOBAL_EVENT_MULTIPLIER) + influence,
+            ),
+            Self::Pandemic => mega_credit_loss(
+                3 * (min(game.tag(Tag::Builder), MAX_GLOBAL_EVENT_MULTIPLIER) - influence),
+            ),
+            Self::WarOnEarth => Mutation::TR(-max(4 - influence, 0)),
+            Self::Riots => mega_credit_loss(
+                4 * (min(
+                    game.board().owned_tile_count(Tile::City, SOLO_PLAYER) as i32,
+                    MAX_GLOBAL_EVENT_MULTIPLIER,
+                ) - influence),
+            ),
+            Self::EcoSabotage => Mutation::Resource(
+                Resource::Plant,
+                -max(game.resource(&Resource::Plant) - 3 - influence, 0),
+            ),
+        }
+    }
+}
+
+impl Display for GlobalEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/**
+The political state of the game: the parties' delegates, the ruling party and the global events
+*/
+#[derive(Clone, Debug)]
+pub struct Turmoil {
+    ruling_party: Party,
+    dominant_party: Party,
+    neutral_delegates: HashMap<Party, i32>,
+    player_delegates: HashMap<Party, i32>,
+    event_deck: Vec<GlobalEvent>,
+    current_event: GlobalEvent,
+    /**
+    Shuffles the event deck, seeded with the setup so that seeded games draw the same events
+    */
+    rng: StdRng,
+}
+
+impl Turmoil {
+    pub(crate) fn new(seed: u64) -> Self {
+        let mut turmoil = Self {
+            ruling_party: Party::Greens,
+            dominant_party: Party::Greens,
+            neutral_delegates: PARTIES.iter().map(|party| (*party, 0)).collect(),
+            player_delegates: PARTIES.iter().map(|party| (*party, 0)).collect(),
+            event_deck: Vec::new(),
+            current_event: GlobalEvent::ScienceFunding,
+            rng: StdRng::seed_from_u64(seed),
+        };
+        turmoil.add_neutral_delegate(Party::Greens);
+        turmoil.reveal_next_event();
+        turmoil
+    }
+
+    pub fn ruling_party(&self) -> Party {
+        self.ruling_party
+    }
+
+    /**
+    The party with the most delegates, which becomes the ruling party at the end of the generation
+    */
+    pub fn dominant_party(&self) -> Party {
+        self.dominant_party
+    }
+
+    pub fn delegates(&self, party: Party) -> i32 {
+        self.neutral_delegates[&party] + self.player_delegates[&party]
+    }
+
+    pub fn player_delegates(&self, party: Party) -> i32 {
+        self.player_delegates[&party]
+    }
+
+    pub fn delegate_reserve(&self) -> i32 {
+        DELEGATES - self.player_delegates.values().sum::<i32>()
+    }
+
+    /**
+    The global event resolved at the end of the current generation
+    */
+    pub fn current_event(&self) -> GlobalEvent {
+        self.current_event
+    }
+
+    /**
+    The player's delegates in the ruling party
+    */
+    pub fn influence(&self) -> i32 {
+        self.player_delegates[&self.ruling_party]
+    }
+
+    pub(crate) fn add_player_delegate(&mut self, party: Party) {
+        assert!(self.delegate_reserve() > 0, "No delegates left in reserve");
+        *self.player_delegates.get_mut(&party).unwrap() += 1;
+        self.update_dominance(party);
+    }
+
+    fn add_neutral_delegate(&mut self, party: Party) {
+        *self.neutral_delegates.get_mut(&party).unwrap() += 1;
+        self.update_dominance(party);
+    }
+
+    /**
+    Ties are won by the party that is already dominant
+    */
+    fn update_dominance(&mut self, party: Party) {
+        if self.delegates(party) > self.delegates(self.dominant_party) {
+            self.dominant_party = party;
+        }
+    }
+
+    fn reveal_next_event(&mut self) {
+        if self.event_deck.is_empty() {
+            self.event_deck = GLOBAL_EVENTS.to_vec();
+            self.event_deck.shuffle(&mut self.rng);
+        }
+        self.current_event = self.event_deck.pop().unwrap();
+        self.add_neutral_delegate(self.current_event.party());
+    }
+
+    /**
+    Hands the government over to the dominant party and reveals the next global event
+    */
+    pub(crate) fn end_generation(&mut self) {
+        self.ruling_party = self.dominant_party;
+        self.reveal_next_event();
+    }
+}
+
+impl Display for Turmoil {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Ruling party: {}; Dominant party: {}; Global event: {}",
+            self.ruling_party, self.dominant_party, self.current_event
+        )?;
+        write!(f, "Delegates (yours / total):")?;
+        for party in PARTIES {
+            write!(
+                f,
+                " {} {} / {};",
+                party,
+                self.player_delegates(party),
+                self.delegates(party)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::game::rule_set::RuleSet;
+    use crate::THARSIS;
+    use rstest::{fixture, rstest};
+
+    #[fixture]
+    fn game() -> Game {
+        Game::new(&THARSIS, RuleSet::default())
+    }
+
+    fn resolve(event: GlobalEvent, game: &mut Game, influence: i32) {
+        let mutation = event.mutation(game, influence);
+        assert!(mutation.apply(game).is_ok());
+    }
+
+    #[test]
+    fn test_delegates_decide_the_dominant_party() {
+        let mut turmoil = Turmoil::new(0);
+        let event_party = turmoil.current_event().party();
+        assert_eq!(turmoil.delegate_reserve(), DELEGATES);
+
+        let party = PARTIES
+            .into_iter()
+            .find(|party| *party != Party::Greens && *party != event_party)
+            .unwrap();
+        turmoil.add_player_delegate(party);
+        assert_ne!(turmoil.dominant_party(), party);
+        turmoil.add_player_delegate(party);
+        turmoil.add_player_delegate(party);
+        assert_eq!(turmoil.dominant_party(), party);
+        assert_eq!(turmoil.delegate_reserve(), DELEGATES - 3);
+
+        turmoil.end_generation();
+        assert_eq!(turmoil.ruling_party(), party);
+        assert_eq!(turmoil.influence(), 3);
+    }
+
+    #[test]
+    fn test_event_deck_is_reshuffled() {
+        let mut turmoil = Turmoil::new(0);
+        for _ in 0..2 * GLOBAL_EVENTS.len() {
+            turmoil.end_generation();
+        }
+        assert_eq!(
+            PARTIES
+                .iter()
+                .map(|party| turmoil.delegates(*party))
+                .sum::<i32>(),
+            1 + 2 * GLOBAL_EVENTS.len() as i32 + 1
+        );
+    }
+
+    #[test]
+    fn test_seeded_event_deck_repeats() {
+        let events = |seed| {
+            let mut turmoil = Turmoil::new(seed);
+            (0..2 * GLOBAL_EVENTS.len())
+                .map(|_| {
+                    turmoil.end_generation();
+                    turmoil.current_event()
+                })
+                .collect::<Vec<GlobalEvent>>()
+        };
+        assert_eq!(events(7), events(7));
+        assert_ne!(events(7), events(8));
+    }
+
+    #[rstest]
+    fn test_influence_adds_to_gains(mut game: Game) {
+        game.tags.insert(Tag::Science, 2);
+        game.tags.insert(Tag::Jovian, 7);
+        let mega_credits = game.resource(&Resource::MegaCredit);
+
+        resolve(GlobalEvent::ScienceFunding, &mut game, 3);
+        assert_eq!(game.resource(&Resource::MegaCredit), mega_credits + 2 + 3);
+        resolve(GlobalEvent::AsteroidMining, &mut game, 1);
+        assert_eq!(
+            game.resource(&Resource::Titanium),
+            MAX_GLOBAL_EVENT_MULTIPLIER + 1
+        );
+    }
+
+    #[rstest]
+    fn test_influence_reduces_losses(mut game: Game) {
+        game.tags.insert(Tag::Builder, 2);
+        game.resources.insert(Resource::MegaCredit, 100);
+        resolve(GlobalEvent::Pandemic, &mut game, 1);
+        assert_eq!(game.resource(&Resource::MegaCredit), 100 - 3);
+        resolve(GlobalEvent::Pandemic, &mut game, 3);
+        assert_eq!(game.resource(&Resource::MegaCredit), 100 - 3);
+
+        game.resources.insert(Resource::Plant, 10);
+        resolve(GlobalEvent::EcoSabotage, &mut game, 2);
+        assert_eq!(game.resource(&Resource::Plant), 3 + 2);
+        resolve(GlobalEvent::EcoSabotage, &mut game, 0);
+        assert_eq!(game.resource(&Resource::Plant), 3);
+    }
+
+    #[rstest]
+    fn test_losses_are_capped_at_what_the_player_has(mut game: Game) {
+        game.tags.insert(Tag::Builder, 7);
+        game.resources.insert(Resource::MegaCredit, 10);
+        resolve(GlobalEvent::Pandemic, &mut game, 0);
+        assert_eq!(game.resource(&Resource::MegaCredit), 0);
+
+        game.resources.insert(Resource::MegaCredit, 100);
+        resolve(GlobalEvent::Pandemic, &mut game, 0);
+        assert_eq!(
+            game.resource(&Resource::MegaCredit),
+            100 - 3 * MAX_GLOBAL_EVENT_MULTIPLIER
+        );
+
+        game.board.place_tile(Tile::City, SOLO_PLAYER);
+        game.board.place_tile(Tile::City, SOLO_PLAYER);
+        game.resources.insert(Resource::MegaCredit, 5);
+        resolve(GlobalEvent::Riots, &mut game, 0);
+        assert_eq!(game.resource(&Resource::MegaCredit), 0);
+
+        game.resources.insert(Resource::MegaCredit, 100);
+        resolve(GlobalEvent::Riots, &mut game, 1);
+        assert_eq!(game.resource(&Resource::MegaCredit), 100 - 4);
+    }
+
+    #[rstest]
+    fn test_war_on_earth_costs_tr(mut game: Game) {
+        let tr = game.tr();
+        resolve(GlobalEvent::WarOnEarth, &mut game, 0);
+        assert_eq!(game.tr(), tr - 4);
+        resolve(GlobalEvent::WarOnEarth, &mut game, 3);
+        assert_eq!(game.tr(), tr - 4 - 1);
+        resolve(GlobalEvent::WarOnEarth, &mut game, 5);
+        assert_eq!(game.tr(), tr - 4 - 1);
+    }
+}
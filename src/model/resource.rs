@@ -14,3 +14,14 @@ pub enum Resource {
 lazy_static! {
     pub static ref RESOURCES: [Resource; 6] = [MegaCredit, Steel, Titanium, Plant, Energy, Heat];
 }
+
+/**
+Resources held on played cards rather than in the player's stock
+*/
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum CardResource {
+    Microbe,
+    Animal,
+    Science,
+    Floater,
+}
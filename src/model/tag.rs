@@ -1,16 +1,31 @@
+use crate::model::tag::Tag::*;
+use lazy_static::lazy_static;
 use std::fmt::{Display, Formatter};
 
 #[derive(Clone, Eq, PartialEq, Hash, Debug, Copy)]
-pub(crate) enum Tag {
+pub enum Tag {
     Builder,
     Space,
     Earth,
     Jovian,
     Plant,
     Microbe,
+    Animal,
     Power,
     Science,
     City,
+    Venus,
+    /// Counted once for every event card played
+    Event,
+    /// Counts as a tag of any type when checking tag requirements
+    Wild,
+}
+
+lazy_static! {
+    pub static ref TAGS: [Tag; 13] = [
+        Builder, Space, Earth, Jovian, Plant, Microbe, Animal, Power, Science, City, Venus, Event,
+        Wild
+    ];
 }
 
 impl Display for Tag {
@@ -2,12 +2,14 @@ use trm_sim::*;
 
 #[test]
 fn standard_asteroid_test() {
-    let mut game = Game::new(&THARSIS);
+    let mut game = Game::new(&THARSIS, RuleSet::default());
     let mut temperature_increases = 0;
 
     while !game.is_over() {
         while game.temperature() < MAX_TEMPERATURE
-            && Action::StandardAsteroid.execute(&mut game).is_ok()
+            && Action::StandardProject(StandardProjectKind::Asteroid)
+                .execute(&mut game)
+                .is_ok()
         {
             temperature_increases += 1;
         }
@@ -19,3 +21,296 @@ fn standard_asteroid_test() {
         temperature_increases * 2
     );
 }
+
+#[test]
+fn final_greenery_conversion_test() {
+    let mut game = Game::new(&THARSIS, RuleSet::default());
+    while game.generation() <= LAST_GENERATION {
+        assert_eq!(game.phase(), Phase::Generation);
+        assert!(Action::Pass.execute(&mut game).is_ok());
+    }
+
+    assert_eq!(game.phase(), Phase::FinalGreeneryConversion);
+    assert!(!game.is_over());
+    assert!(!game.is_won());
+    assert!(Action::StandardProject(StandardProjectKind::Asteroid)
+        .execute(&mut game)
+        .is_err());
+    assert!(Action::HeatConversion.execute(&mut game).is_err());
+
+    let plants = game.resource(&Resource::Plant);
+    let oxygen = game.oxygen();
+    assert!(Action::PlantConversion.execute(&mut game).is_ok());
+    assert_eq!(game.resource(&Resource::Plant), plants - 8);
+    assert_eq!(game.oxygen(), oxygen + 1);
+
+    assert!(Action::Pass.execute(&mut game).is_ok());
+    assert!(game.is_over());
+    assert!(Action::PlantConversion.execute(&mut game).is_err());
+    assert_eq!(
+        Action::SellPatents(vec![])
+            .execute(&mut game)
+            .unwrap_err()
+            .to_string(),
+        "Game is over"
+    );
+}
+
+#[test]
+fn custom_rule_set_test() {
+    let rules = RuleSet {
+        last_generation: 3,
+        initial_tr: 20,
+        standard_asteroid_cost: 10,
+        ..RuleSet::default()
+    };
+    let mut game = Game::new(&THARSIS, rules);
+    assert_eq!(game.tr(), 20);
+
+    let mega_credits = game.resource(&Resource::MegaCredit);
+    assert!(Action::StandardProject(StandardProjectKind::Asteroid)
+        .execute(&mut game)
+        .is_ok());
+    assert_eq!(game.resource(&Resource::MegaCredit), mega_credits - 10);
+
+    let mut passes = 0;
+    while !game.is_over() {
+        assert!(Action::Pass.execute(&mut game).is_ok());
+        passes += 1;
+    }
+    assert_eq!(passes, 4);
+}
+
+#[test]
+fn sell_patents_test() {
+    let mut game = Game::new(&THARSIS, RuleSet::default());
+    let mega_credits = game.resource(&Resource::MegaCredit);
+    let card_ids: Vec<CardId> = game.cards_in_hand().iter().take(3).copied().collect();
+
+    assert!(Action::SellPatents(vec![]).execute(&mut game).is_err());
+    assert!(Action::SellPatents(vec![card_ids[0], card_ids[0]])
+        .execute(&mut game)
+        .is_err());
+    assert!(Action::SellPatents(vec![card_ids[0], -1])
+        .execute(&mut game)
+        .is_err());
+    assert_eq!(game.cards_in_hand().len(), 10);
+    assert_eq!(game.resource(&Resource::MegaCredit), mega_credits);
+
+    assert!(Action::SellPatents(card_ids.clone())
+        .execute(&mut game)
+        .is_ok());
+    assert_eq!(game.cards_in_hand().len(), 7);
+    assert_eq!(game.discarded_cards(), &card_ids[..]);
+    assert_eq!(game.resource(&Resource::MegaCredit), mega_credits + 3);
+}
+
+#[test]
+fn venus_next_test() {
+    let mut game = Game::new(&THARSIS, RuleSet::default());
+    assert!(Action::StandardProject(StandardProjectKind::AirScrapping)
+        .execute(&mut game)
+        .is_err());
+
+    let rules = RuleSet {
+        venus_next: true,
+        ..RuleSet::default()
+    };
+    let mut game = Game::new(&THARSIS, rules);
+    let tr = game.tr();
+    assert_eq!(game.venus(), INITIAL_VENUS);
+    assert!(Action::StandardProject(StandardProjectKind::AirScrapping)
+        .execute(&mut game)
+        .is_ok());
+    assert_eq!(game.venus(), INITIAL_VENUS + 2);
+    assert_eq!(game.tr(), tr + 1);
+    assert_eq!(
+        game.resource(&Resource::MegaCredit),
+        INITIAL_MEGA_CREDITS - rules.standard_air_scrapping_cost
+    );
+}
+
+#[test]
+fn prelude_phase_test() {
+    let rules = RuleSet {
+        preludes: true,
+        ..RuleSet::default()
+    };
+    let mut game = Game::new(&THARSIS, rules);
+    assert_eq!(game.phase(), Phase::Prelude);
+    assert_eq!(game.preludes_in_hand().len(), PRELUDES_DEALT);
+    assert!(Action::Pass.execute(&mut game).is_err());
+    assert!(Action::StandardProject(StandardProjectKind::PowerPlant)
+        .execute(&mut game)
+        .is_err());
+    assert!(Action::Prelude(-1).execute(&mut game).is_err());
+
+    // Donation and Power Generation can always be played
+    let mut preludes: Vec<CardId> = game.preludes_in_hand().iter().copied().collect();
+    preludes.sort_by_key(|id| ![8, 27].contains(id));
+    let mut played = 0;
+    for prelude in preludes {
+        if Action::Prelude(prelude).execute(&mut game).is_ok() {
+            played += 1;
+        }
+        if played == PRELUDES_TO_PLAY {
+            break;
+        }
+    }
+
+    assert_eq!(played, PRELUDES_TO_PLAY);
+    assert_eq!(game.phase(), Phase::Generation);
+    assert_eq!(game.played_preludes().len(), PRELUDES_TO_PLAY);
+    assert!(game.preludes_in_hand().is_empty());
+    assert_eq!(game.generation(), 1);
+    assert!(Action::Pass.execute(&mut game).is_ok());
+}
+
+#[test]
+fn colonies_test() {
+    let rules = RuleSet {
+        colonies: true,
+        ..RuleSet::default()
+    };
+    let mut game = Game::new(&THARSIS, rules);
+    assert_eq!(game.colonies().len(), rules.colony_tiles_in_play);
+    assert_eq!(game.available_trade_fleets(), rules.trade_fleets);
+
+    let tile = game.colonies()[0].tile();
+    assert!(Action::Trade(tile, Resource::Steel)
+        .execute(&mut game)
+        .is_err());
+    assert!(Action::BuildColony(tile).execute(&mut game).is_ok());
+    assert_eq!(game.colonies()[0].colonies(), 1);
+    assert_eq!(
+        game.resource(&Resource::MegaCredit),
+        INITIAL_MEGA_CREDITS - rules.standard_colony_cost
+    );
+
+    assert!(Action::Trade(tile, Resource::MegaCredit)
+        .execute(&mut game)
+        .is_ok());
+    assert_eq!(game.available_trade_fleets(), 0);
+    assert_eq!(game.colonies()[0].track_position(), 1);
+
+    let other_tile = game.colonies()[1].tile();
+    assert!(Action::Trade(other_tile, Resource::MegaCredit)
+        .execute(&mut game)
+        .is_err());
+}
+
+#[test]
+fn trading_with_pluto_every_generation_test() {
+    let rules = RuleSet {
+        colonies: true,
+        colony_tiles_in_play: COLONY_TILES.len(),
+        ..RuleSet::default()
+    };
+    let mut game = Game::new(&THARSIS, rules);
+    assert!(Action::BuildColony(ColonyTile::Pluto)
+        .execute(&mut game)
+        .is_ok());
+    assert!(Action::BuildColony(ColonyTile::Pluto)
+        .execute(&mut game)
+        .is_err());
+
+    let mut undrawn_cards = 0;
+    while !game.is_over() {
+        if game.phase() == Phase::Generation {
+            let outcome = Action::Trade(ColonyTile::Pluto, Resource::MegaCredit)
+                .execute(&mut game)
+                .unwrap();
+            undrawn_cards += outcome.undrawn_cards();
+        }
+        undrawn_cards += Action::Pass.execute(&mut game).unwrap().undrawn_cards();
+    }
+    assert!(undrawn_cards > 0);
+}
+
+#[test]
+fn turmoil_test() {
+    let rules = RuleSet {
+        turmoil: true,
+        setup_seed: Some(3),
+        ..RuleSet::default()
+    };
+    let mut game = Game::new(&THARSIS, rules);
+    assert_eq!(game.turmoil().unwrap().ruling_party(), Party::Greens);
+    assert_eq!(
+        game.turmoil().unwrap().current_event(),
+        Game::new(&THARSIS, rules)
+            .turmoil()
+            .unwrap()
+            .current_event()
+    );
+
+    for _ in 0..DELEGATES {
+        assert!(Action::SendDelegate(Party::Scientists)
+            .execute(&mut game)
+            .is_ok());
+    }
+    assert!(Action::SendDelegate(Party::Scientists)
+        .execute(&mut game)
+        .is_err());
+    assert_eq!(
+        game.resource(&Resource::MegaCredit),
+        INITIAL_MEGA_CREDITS - DELEGATES * rules.delegate_cost
+    );
+    assert_eq!(game.turmoil().unwrap().dominant_party(), Party::Scientists);
+
+    // The first event is resolved while the Greens, where the player has no delegates, still rule
+    assert!(Action::Pass.execute(&mut game).is_ok());
+    let tr = game.tr();
+    let mut events = Vec::new();
+    while !game.is_over() {
+        assert_eq!(game.turmoil().unwrap().ruling_party(), Party::Scientists);
+        assert_eq!(game.turmoil().unwrap().influence(), DELEGATES);
+        events.push(game.turmoil().unwrap().current_event());
+        assert!(Action::Pass.execute(&mut game).is_ok());
+    }
+    // With full influence, not even War on Earth costs TR
+    assert!(events.contains(&GlobalEvent::WarOnEarth));
+    assert_eq!(game.tr(), tr);
+}
+
+#[test]
+fn solo_setup_test() {
+    let rules = RuleSet {
+        setup_seed: Some(7),
+        ..RuleSet::default()
+    };
+    let game = Game::new(&THARSIS, rules);
+    assert_eq!(game.score_breakdown().greenery_tiles(), 0);
+    assert_eq!(game.score_breakdown().city_adjacency(), 0);
+    assert_eq!(game.victory_points(), rules.initial_tr);
+    assert!(!Milestone::Mayor.is_reached(&game));
+}
+
+#[test]
+fn rule_set_validation_test() {
+    let rules = RuleSet {
+        max_oceans: 13,
+        ..RuleSet::default()
+    };
+    assert!(Game::try_new(&THARSIS, rules).is_err());
+    assert!(Game::try_new(&THARSIS, RuleSet::default()).is_ok());
+}
+
+#[test]
+fn simulation_test() {
+    let game = Game::new(&THARSIS, RuleSet::default());
+    let mega_credits = game.resource(&Resource::MegaCredit);
+    let card_ids: Vec<CardId> = game.cards_in_hand().iter().take(2).copied().collect();
+
+    let (simulated_game, outcome) = Action::SellPatents(card_ids).simulate(&game).unwrap();
+    assert_eq!(outcome.resource(&Resource::MegaCredit), 2);
+    assert_eq!(simulated_game.cards_in_hand().len(), 8);
+    assert_eq!(
+        simulated_game.resource(&Resource::MegaCredit),
+        mega_credits + 2
+    );
+    assert_eq!(game.cards_in_hand().len(), 10);
+    assert_eq!(game.resource(&Resource::MegaCredit), mega_credits);
+
+    assert!(Action::SellPatents(vec![]).simulate(&game).is_err());
+}